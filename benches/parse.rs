@@ -0,0 +1,95 @@
+//! Compares `advent_2025::parse` against plain `str::parse`
+//!
+//! Run with `cargo bench --bench parse`
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_2025::parse::{Scanner, digit_grid};
+
+const ROUNDS: u32 = 20;
+
+fn bench(name: &str, mut f: impl FnMut() -> u64) {
+    // warm up
+    black_box(f());
+
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f());
+        total += start.elapsed();
+    }
+
+    println!("{name:<24} {:>10.3?}", total / ROUNDS);
+}
+
+/// Day08-like input, three comma separated numbers per line
+fn points(n: u64) -> String {
+    let mut out = String::new();
+    for i in 0..n {
+        let x = i.wrapping_mul(2654435761) % 100_000;
+        writeln!(out, "{},{},{}", x, x * 7 % 99_991, i).unwrap();
+    }
+    out
+}
+
+/// Day03-like input, lines of digits
+fn digits(lines: u64, width: u64) -> String {
+    let mut out = String::new();
+    for row in 0..lines {
+        for col in 0..width {
+            out.push(char::from_digit(((row * 31 + col * 17) % 9 + 1) as u32, 10).unwrap());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn main() {
+    let input = points(200_000);
+
+    bench("points/str", || {
+        input
+            .lines()
+            .flat_map(|line| line.split(','))
+            .map(|field| field.parse::<u64>().unwrap())
+            .sum()
+    });
+
+    bench("points/scanner", || {
+        Scanner::new(input.as_bytes())
+            .lines()
+            .flat_map(|line| line.split(b','))
+            .map(|mut field| field.u64().unwrap())
+            .sum()
+    });
+
+    bench("points/integers", || {
+        Scanner::new(input.as_bytes())
+            .integers()
+            .map(|num| num.unwrap() as u64)
+            .sum()
+    });
+
+    let input = digits(10_000, 100);
+
+    bench("digits/str", || {
+        input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|chr| chr.to_digit(10).unwrap() as u8)
+                    .collect::<Vec<u8>>()
+            })
+            .map(|bank| bank.iter().map(|&d| d as u64).sum::<u64>())
+            .sum()
+    });
+
+    bench("digits/digit_grid", || {
+        let grid = digit_grid(input.as_bytes()).unwrap();
+        grid.iter_indexed().map(|(_, &d)| d as u64).sum()
+    });
+}
@@ -0,0 +1,263 @@
+//! Puzzle answers which never silently wrap around
+//!
+//! Solvers can be generic over [`Answer`], so the same code runs with `u64`, `u128`
+//! or the unbounded [`BigUint`]. The `checked_*` helpers report which operation
+//! overflowed instead of producing a wrong number.
+//!
+//! ```
+//! use advent_2025::answer::{Answer, Overflow, checked_product};
+//!
+//! fn volume<T: Answer>(sides: &[u64]) -> Result<T, Overflow> {
+//!     checked_product(sides.iter().map(|&side| T::from(side)), "volume")
+//! }
+//!
+//! let sides = [u64::MAX, 3];
+//! assert!(volume::<u64>(&sides).is_err());
+//! assert_eq!(volume::<u128>(&sides), Ok(3 * u64::MAX as u128));
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display},
+};
+
+/// Number type an answer can be computed in
+pub trait Answer: Clone + Debug + Display + From<u64> {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_answer {
+    ($($ty:ty),+) => {
+        $(
+            impl Answer for $ty {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_add(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$ty>::checked_mul(*self, *rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_answer!(u64, u128);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+/// Arithmetic which didn't fit into the answer type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    /// What was being computed, e.g. `"sum of path counts"`
+    pub context: &'static str,
+    pub operation: Operation,
+    pub lhs: String,
+    pub rhs: String,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self.operation {
+            Operation::Add => '+',
+            Operation::Multiply => '*',
+        };
+
+        write!(
+            f,
+            "{} overflowed at {} {symbol} {}",
+            self.context, self.lhs, self.rhs
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+fn overflow<T: Answer>(operation: Operation, lhs: &T, rhs: &T, context: &'static str) -> Overflow {
+    Overflow {
+        context,
+        operation,
+        lhs: lhs.to_string(),
+        rhs: rhs.to_string(),
+    }
+}
+
+pub fn checked_add<T: Answer>(lhs: &T, rhs: &T, context: &'static str) -> Result<T, Overflow> {
+    lhs.checked_add(rhs)
+        .ok_or_else(|| overflow(Operation::Add, lhs, rhs, context))
+}
+
+pub fn checked_mul<T: Answer>(lhs: &T, rhs: &T, context: &'static str) -> Result<T, Overflow> {
+    lhs.checked_mul(rhs)
+        .ok_or_else(|| overflow(Operation::Multiply, lhs, rhs, context))
+}
+
+pub fn checked_sum<T: Answer>(
+    values: impl IntoIterator<Item = T>,
+    context: &'static str,
+) -> Result<T, Overflow> {
+    values
+        .into_iter()
+        .try_fold(T::from(0), |sum, value| checked_add(&sum, &value, context))
+}
+
+pub fn checked_product<T: Answer>(
+    values: impl IntoIterator<Item = T>,
+    context: &'static str,
+) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::from(1), |product, value| {
+        checked_mul(&product, &value, context)
+    })
+}
+
+/// Unsigned integer without an upper bound
+///
+/// Only supports what answers need, i.e. addition, multiplication and printing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without trailing zeros
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn trim(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(rhs.limbs.len()) + 1);
+        let mut carry = 0;
+
+        for i in 0..self.limbs.len().max(rhs.limbs.len()) {
+            let a = self.limbs.get(i).copied().unwrap_or(0) as u64;
+            let b = rhs.limbs.get(i).copied().unwrap_or(0) as u64;
+            let sum = a + b + carry;
+
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+
+        Self { limbs }.trim()
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        let mut limbs = vec![0u32; self.limbs.len() + rhs.limbs.len()];
+
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                // can't overflow: (2^32 - 1)^2 + 2 * (2^32 - 1) < 2^64
+                let product = a as u64 * b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+
+        Self { limbs }.trim()
+    }
+
+    /// Divides in place and returns the remainder
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut rem = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (rem << 32) | *limb as u64;
+            *limb = (current / divisor as u64) as u32;
+            rem = current % divisor as u64;
+        }
+
+        *self = std::mem::take(self).trim();
+        rem as u32
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        }
+        .trim()
+    }
+}
+
+impl Answer for BigUint {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.add(rhs))
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.mul(rhs))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+
+        // nine decimal digits at a time, least significant first
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        while !rest.limbs.is_empty() {
+            chunks.push(rest.div_rem_small(CHUNK));
+        }
+
+        let Some((most, others)) = chunks.split_last() else {
+            return f.write_str("0");
+        };
+
+        write!(f, "{most}")?;
+        for chunk in others.iter().rev() {
+            write!(f, "{chunk:09}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_overflowing_operation() {
+        let err = checked_mul(&u64::MAX, &2, "product of path counts").unwrap_err();
+
+        assert_eq!(err.operation, Operation::Multiply);
+        assert_eq!(
+            err.to_string(),
+            "product of path counts overflowed at 18446744073709551615 * 2"
+        );
+        assert_eq!(checked_sum([u64::MAX - 1, 1], "sum"), Ok(u64::MAX));
+        assert!(checked_sum([u64::MAX, 1], "sum").is_err());
+    }
+
+    #[test]
+    fn widens_to_u128() {
+        let product: u128 = checked_product([u64::MAX, u64::MAX].map(u128::from), "").unwrap();
+
+        assert_eq!(product, (u64::MAX as u128) * (u64::MAX as u128));
+    }
+
+    #[test]
+    fn big_numbers_never_overflow() {
+        let big = BigUint::from(u64::MAX);
+        let product = checked_product([big.clone(), big.clone(), big], "").unwrap();
+        let sum = checked_sum([BigUint::from(999_999_999), BigUint::from(1)], "").unwrap();
+
+        assert_eq!(
+            product.to_string(),
+            "6277101735386680762814942322444851025767571854389858533375"
+        );
+        assert_eq!(sum.to_string(), "1000000000");
+        assert_eq!(BigUint::from(0).to_string(), "0");
+    }
+}
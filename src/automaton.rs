@@ -0,0 +1,264 @@
+//! Cellular automata on top of [`Grid`]
+//!
+//! A rule looks at a cell and its neighbourhood and returns the cell's next state.
+//! Every step is synchronous, i.e. the rule always sees the previous generation.
+
+use crate::{Addressing, Grid, GridEntry};
+
+/// All eight surrounding cells
+pub const MOORE: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Four orthogonally adjacent cells
+pub const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Neighbours of a single cell as seen by a rule
+pub struct Neighbourhood<'a, T> {
+    entry: GridEntry<'a, T>,
+    offsets: &'a [(isize, isize)],
+}
+
+impl<'a, T> Neighbourhood<'a, T> {
+    /// Neighbours which are inside the grid
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.offsets
+            .iter()
+            .filter_map(|&(dx, dy)| self.entry.at_offset(dx, dy))
+    }
+
+    pub fn count(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|t| pred(t)).count()
+    }
+}
+
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    /// Back buffer for synchronous steps
+    next: Grid<T>,
+    rule: R,
+    offsets: &'static [(isize, isize)],
+    addressing: Addressing,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: Fn(&T, &Neighbourhood<'_, T>) -> T,
+{
+    /// Creates an automaton using the [`MOORE`] neighbourhood
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            rule,
+            offsets: &MOORE,
+            addressing: Addressing::Bounded,
+            generation: 0,
+        }
+    }
+
+    /// Use some other neighbourhood, e.g. [`VON_NEUMANN`]
+    ///
+    /// Incremental updates rely on the rule only looking at these offsets.
+    pub fn with_neighbourhood(mut self, offsets: &'static [(isize, isize)]) -> Self {
+        self.offsets = offsets;
+        self
+    }
+
+    /// How neighbours past the edges are found, e.g. [`Addressing::Wrapping`] for a torus
+    pub fn with_addressing(mut self, addressing: Addressing) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// Number of steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn apply_rule(&self, (col, row): (usize, usize)) -> T {
+        let neighbourhood = Neighbourhood {
+            entry: self
+                .current
+                .entry(col, row)
+                .with_addressing(self.addressing),
+            offsets: self.offsets,
+        };
+
+        (self.rule)(&self.current[(col, row)], &neighbourhood)
+    }
+
+    /// Advances every cell by one generation
+    ///
+    /// Returns the number of cells which changed
+    pub fn step(&mut self) -> usize {
+        let mut changed = 0;
+
+        for row in 0..self.current.height() {
+            for col in 0..self.current.width() {
+                let new = self.apply_rule((col, row));
+                if self.current[(col, row)] != new {
+                    changed += 1;
+                }
+
+                self.next[(col, row)] = new;
+            }
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    /// Steps until nothing changes
+    ///
+    /// Returns the number of steps which changed something,
+    /// the final no-op step is not counted in [`Self::generation`].
+    pub fn run_until_stable(&mut self) -> usize {
+        let start = self.generation;
+
+        while self.step() > 0 {}
+        self.generation -= 1;
+
+        self.generation - start
+    }
+
+    /// Like [`Self::run_until_stable`], but after the first generation
+    /// only cells next to ones that changed are evaluated again
+    ///
+    /// Much faster when changes are local, gives the same result as long as
+    /// the rule only depends on the cell and its neighbourhood.
+    pub fn run_incremental(&mut self) -> usize {
+        let start = self.generation;
+        let (width, height) = (self.current.width(), self.current.height());
+
+        let mut queued = Grid::from_vec_and_dimensions(vec![true; width * height], width, height);
+        let mut worklist: Vec<(usize, usize)> = queued.iter_indexed().map(|(pos, _)| pos).collect();
+        let mut changes = Vec::new();
+
+        while !worklist.is_empty() {
+            // evaluate everything against the current generation first...
+            for &pos in &worklist {
+                let new = self.apply_rule(pos);
+                if self.current[pos] != new {
+                    changes.push((pos, new));
+                }
+            }
+
+            for &(col, row) in &worklist {
+                queued[(col, row)] = false;
+            }
+            worklist.clear();
+
+            if changes.is_empty() {
+                break;
+            }
+
+            // ...then apply and queue everything which could see the change
+            for ((col, row), new) in changes.drain(..) {
+                self.current[(col, row)] = new;
+
+                let around = self.offsets.iter().map(|&(dx, dy)| (-dx, -dy));
+                for offset in std::iter::once((0, 0)).chain(around) {
+                    let Some((true_col, true_row)) =
+                        self.addressing.resolve((col, row), offset, (width, height))
+                    else {
+                        continue;
+                    };
+
+                    let flag = &mut queued[(true_col, true_row)];
+                    if !*flag {
+                        *flag = true;
+                        worklist.push((true_col, true_row));
+                    }
+                }
+            }
+
+            self.generation += 1;
+        }
+
+        // keep back buffer in sync for further synchronous steps
+        self.next.clone_from(&self.current);
+
+        self.generation - start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(grid: &Grid<bool>) -> Vec<bool> {
+        grid.iter_indexed().map(|(_, &alive)| alive).collect()
+    }
+
+    fn life(alive: &bool, neighbours: &Neighbourhood<'_, bool>) -> bool {
+        matches!((alive, neighbours.count(|&n| n)), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let grid = Grid::new(".....\n..#..\n..#..\n..#..\n.....\n", |chr, _| chr == '#');
+        let mut automaton = Automaton::new(grid.clone(), life);
+
+        assert_eq!(automaton.step(), 4);
+        assert!(
+            automaton
+                .grid()
+                .iter_row(2)
+                .eq([false, true, true, true, false])
+        );
+
+        automaton.step();
+        assert_eq!(cells(automaton.grid()), cells(&grid));
+        assert_eq!(automaton.generation(), 2);
+    }
+
+    #[test]
+    fn incremental_matches_synchronous() {
+        // erosion: a cell dies if any orthogonal neighbour is dead or missing
+        let erode = |alive: &bool, neighbours: &Neighbourhood<'_, bool>| {
+            *alive && neighbours.count(|&n| n) == 4
+        };
+        let grid = Grid::from_vec_and_dimensions(vec![true; 7 * 5], 7, 5);
+
+        let mut sync = Automaton::new(grid.clone(), erode).with_neighbourhood(&VON_NEUMANN);
+        let mut incremental = Automaton::new(grid, erode).with_neighbourhood(&VON_NEUMANN);
+
+        assert_eq!(sync.run_until_stable(), 3);
+        assert_eq!(incremental.run_incremental(), 3);
+        assert_eq!(cells(sync.grid()), cells(incremental.grid()));
+        assert!(cells(sync.grid()).iter().all(|&alive| !alive));
+    }
+
+    #[test]
+    fn glider_wraps_around_torus() {
+        let grid = Grid::new(".#...\n..#..\n###..\n.....\n.....\n", |chr, _| chr == '#');
+        let mut automaton =
+            Automaton::new(grid.clone(), life).with_addressing(Addressing::Wrapping);
+
+        // a glider moves one cell diagonally every four steps
+        for _ in 0..4 * 5 {
+            automaton.step();
+        }
+        assert_eq!(cells(automaton.grid()), cells(&grid));
+    }
+}
@@ -1,16 +1,19 @@
 use std::io;
 
-use advent_2025::read_input_from_env;
+use advent_2025::{Input, Params, parse::sections};
 
-fn silver(input: &str) -> u32 {
+/// Where the dial starts and how many numbers it has
+struct Dial {
+    start: usize,
+    size: usize,
+}
+
+fn silver(input: &str, dial: &Dial) -> u32 {
+    let size = dial.size as i32;
     let mut answer = 0;
-    let mut dial: i32 = 50;
+    let mut dial = dial.start as i32;
 
     for line in input.lines() {
-        if line.is_empty() {
-            break;
-        }
-
         let (dir, count) = line.split_at(1);
         let count: i32 = count.parse().unwrap();
 
@@ -20,7 +23,7 @@ fn silver(input: &str) -> u32 {
             _ => count,
         };
 
-        dial = (dial + count).rem_euclid(100);
+        dial = (dial + count).rem_euclid(size);
 
         if dial == 0 {
             answer += 1;
@@ -30,15 +33,12 @@ fn silver(input: &str) -> u32 {
     answer
 }
 
-fn gold(input: &str) -> u32 {
+fn gold(input: &str, dial: &Dial) -> u32 {
+    let size = dial.size as i32;
     let mut answer = 0;
-    let mut dial: i32 = 50;
+    let mut dial = dial.start as i32;
 
     for line in input.lines() {
-        if line.is_empty() {
-            break;
-        }
-
         let (dir, count) = line.split_at(1);
         let count = match count.parse::<i32>() {
             Ok(num) => {
@@ -51,31 +51,53 @@ fn gold(input: &str) -> u32 {
             Err(_) => panic!(),
         };
 
-        // from last iteration, "dial" is [0, 99] here
+        // from last iteration, "dial" is [0, size) here
         let next_dial = dial + count;
 
-        if next_dial >= 100 {
+        if next_dial >= size {
             // positive rotation and crossed zero
-            answer += (next_dial / 100) as u32; // number of zero crossings
+            answer += (next_dial / size) as u32; // number of zero crossings
         } else if next_dial <= 0 {
             // negative rotation
             // if dial was previously at 0 we don't want to count that as a zero crossing
             if dial != 0 {
                 answer += 1;
             }
-            answer += (next_dial.abs() / 100) as u32; // number of full rotations needed to add
+            answer += (next_dial.abs() / size) as u32; // number of full rotations needed to add
         }
 
-        dial = next_dial.rem_euclid(100);
+        dial = next_dial.rem_euclid(size);
     }
 
     answer
 }
 
 fn main() -> io::Result<()> {
-    let input = read_input_from_env()?;
-    println!("silver: {}", silver(&input));
-    println!("gold: {}", gold(&input));
+    // with the `mmap` feature the file is mapped instead of read into memory
+    let input = Input::from_env()?;
+    // rotations end at the first blank line
+    let input = sections(input.as_str()?).next().unwrap_or_default();
+
+    let params = Params::from_env(&[("start", "50"), ("size", "100")])?;
+    let dial = Dial {
+        start: params.get("start")?,
+        size: params.get("size")?,
+    };
+    if dial.size == 0 || dial.size > i32::MAX as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("dial size {} out of range", dial.size),
+        ));
+    }
+    if dial.start >= dial.size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("start {} isn't on a dial of size {}", dial.start, dial.size),
+        ));
+    }
+
+    println!("silver: {}", silver(input, &dial));
+    println!("gold: {}", gold(input, &dial));
 
     Ok(())
 }
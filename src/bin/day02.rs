@@ -1,6 +1,6 @@
 use std::{fmt::Write, io};
 
-use advent_2025::read_input_from_env;
+use advent_2025::{parse::Pattern, read_input_from_env};
 
 #[derive(Debug)]
 struct Range {
@@ -9,18 +9,15 @@ struct Range {
 }
 
 fn parse(input: &str) -> Vec<Range> {
+    let pattern = Pattern::new("{u64}-{u64}");
+
     input
         .trim()
         .split(',')
         .filter_map(|range| {
-            let (start, end) = range.split_once('-')?;
-
-            let range = Range {
-                start: start.parse().ok()?,
-                end: end.parse().ok()?,
-            };
+            let (start, end) = pattern.parse(range).ok()?;
 
-            Some(range)
+            Some(Range { start, end })
         })
         .collect()
 }
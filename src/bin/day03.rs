@@ -1,27 +1,27 @@
 use std::io;
 
-use advent_2025::read_input_from_env;
+use advent_2025::{Params, parse::Scanner, read_lines_from_env};
 
-fn solve<const N: usize>(banks: &Vec<Vec<u8>>) -> u64 {
-    banks.into_iter().map(|bank| joltage::<N>(bank)).sum()
-}
-
-/// Calculate joltage of N batteries in given bank
-fn joltage<const N: usize>(bank: &[u8]) -> u64 {
+/// Calculate joltage of n batteries in given bank
+fn joltage(bank: &[u8], batteries: usize) -> u64 {
     let mut joltage = 0;
 
     // bank index where we can start looking for next maximum
     let mut cursor = 0;
 
-    for n in 0..N {
+    for n in 0..batteries {
         let mut best = 0;
 
         // can't look till end since then there wouldn't be space for rest of the digits
-        for i in cursor..bank.len() - N + n + 1 {
+        let start = cursor;
+        for (i, &battery) in bank[start..bank.len() - batteries + n + 1]
+            .iter()
+            .enumerate()
+        {
             // >= would give last best, we need first best instead
-            if bank[i] > best {
-                best = bank[i];
-                cursor = i + 1;
+            if battery > best {
+                best = battery;
+                cursor = start + i + 1;
 
                 if best == 9 {
                     // cannot improve this number any more
@@ -37,19 +37,42 @@ fn joltage<const N: usize>(bank: &[u8]) -> u64 {
 }
 
 fn main() -> io::Result<()> {
-    let input = read_input_from_env()?;
-    let banks: Vec<Vec<u8>> = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|chr| chr.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect();
-
-    println!("silver: {}", solve::<2>(&banks));
-    println!("gold: {}", solve::<12>(&banks));
+    let params = Params::from_env(&[("silver_batteries", "2"), ("gold_batteries", "12")])?;
+    let silver_batteries = params.get::<usize>("silver_batteries")?;
+    let gold_batteries = params.get::<usize>("gold_batteries")?;
+    let most_batteries = usize::max(silver_batteries, gold_batteries);
+
+    let mut silver = 0;
+    let mut gold = 0;
+
+    // banks are independent of each other,
+    // so there's no need to keep more than one of them in memory
+    let mut bank = Vec::new();
+    for line in read_lines_from_env()? {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        bank.clear();
+        let mut digits = Scanner::new(line.as_bytes());
+        while !digits.is_empty() {
+            bank.push(digits.digit().unwrap());
+        }
+        if bank.len() < most_batteries {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("bank {line:?} has fewer than {most_batteries} batteries"),
+            ));
+        }
+
+        silver += joltage(&bank, silver_batteries);
+        gold += joltage(&bank, gold_batteries);
+    }
+
+    println!("silver: {}", silver);
+    println!("gold: {}", gold);
 
     Ok(())
 }
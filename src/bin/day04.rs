@@ -1,13 +1,35 @@
-use std::io;
+use std::{
+    fmt::{Display, Write},
+    fs::File,
+    io::{self, BufWriter},
+    time::Duration,
+};
 
-use advent_2025::{Grid, read_input_from_env};
+use advent_2025::{
+    Grid, Params,
+    automaton::{Automaton, Neighbourhood},
+    grid::{
+        image::Image,
+        render::{Color, Style, animate},
+    },
+    read_input_from_env,
+};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Tile {
     Empty,
     Roll,
 }
 
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Empty => f.write_char('.'),
+            Tile::Roll => f.write_char('@'),
+        }
+    }
+}
+
 fn check_tile(grid: &Grid<Tile>, col: usize, row: usize) -> bool {
     let checked_directions: [(isize, isize); 8] = [
         (0, -1),
@@ -30,48 +52,87 @@ fn check_tile(grid: &Grid<Tile>, col: usize, row: usize) -> bool {
 }
 
 fn silver(grid: &Grid<Tile>) -> usize {
-    grid.iter_indexed()
-        .filter(|((col, row), tile)| matches!(tile, Tile::Roll) && check_tile(grid, *col, *row))
+    grid.positions_by(|tile| *tile == Tile::Roll)
+        .filter(|&(col, row)| check_tile(grid, col, row))
         .count()
 }
 
-fn gold(grid: &mut Grid<Tile>) -> usize {
-    let mut total = 0;
+fn gold(grid: &Grid<Tile>) -> usize {
+    let rolls = |grid: &Grid<Tile>| grid.count_by(|tile| *tile == Tile::Roll);
+
+    // removing rolls only ever makes more rolls removable,
+    // so only neighbours of removed rolls need to be checked again
+    let mut automaton = Automaton::new(grid.clone(), removal_rule);
+    automaton.run_incremental();
+
+    rolls(grid) - rolls(automaton.grid())
+}
+
+fn removal_rule(tile: &Tile, neighbours: &Neighbourhood<'_, Tile>) -> Tile {
+    match tile {
+        Tile::Roll if neighbours.count(|n| *n == Tile::Roll) < 4 => Tile::Empty,
+        _ => *tile,
+    }
+}
+
+/// Animate removal rounds in the terminal, rolls about to be removed are highlighted
+fn visualize(grid: &Grid<Tile>) -> io::Result<()> {
+    let mut automaton = Automaton::new(grid.clone(), removal_rule);
+    let mut frames = Vec::new();
 
     loop {
-        let removed = grid
-            .iter_indexed()
-            .filter_map(|((col, row), tile)| {
-                let for_removal = matches!(tile, Tile::Roll) && check_tile(grid, col, row);
+        let before = automaton.grid().clone();
+        let changed = automaton.step();
 
-                // filter_map since we need to give up &tile reference
-                if for_removal { Some((col, row)) } else { None }
+        let after = automaton.grid();
+        let frame = before
+            .render(|(col, row), tile| match (tile, after.at(col, row)) {
+                (Tile::Roll, Some(Tile::Empty)) => Style::new().fg(Color::Red).bold(),
+                (Tile::Roll, _) => Style::new().fg(Color::Green),
+                _ => Style::new(),
             })
-            .collect::<Vec<_>>();
+            .to_string();
+        frames.push(frame);
 
-        if removed.len() == 0 {
+        if changed == 0 {
             break;
         }
-        total += removed.len();
-
-        for (rm_c, rm_r) in removed {
-            *grid.at_mut(rm_c, rm_r).unwrap() = Tile::Empty;
-        }
     }
 
-    total
+    animate(&mut io::stdout(), frames, Duration::from_millis(200))
+}
+
+/// Save the grid as a PPM image, rolls which can be removed right away are highlighted
+fn export_image(grid: &Grid<Tile>, path: &str) -> io::Result<()> {
+    let image = Image::from_grid(grid, 4, |(col, row), tile| match tile {
+        Tile::Roll if check_tile(grid, col, row) => [220, 60, 60],
+        Tile::Roll => [40, 200, 40],
+        Tile::Empty => [20, 20, 20],
+    });
+
+    image.write_ppm(BufWriter::new(File::create(path)?))
 }
 
 fn main() -> io::Result<()> {
+    let params = Params::from_env(&[("visualize", "false"), ("image", "")])?;
     let input = read_input_from_env()?;
-    let mut grid = Grid::new(&input, |chr, _| match chr {
+    let grid = Grid::new(&input, |chr, _| match chr {
         '.' => Tile::Empty,
         '@' => Tile::Roll,
         _ => panic!("invalid tile in input"),
     });
 
+    if params.get("visualize")? {
+        visualize(&grid)?;
+    }
+
+    let image = params.get_str("image");
+    if !image.is_empty() {
+        export_image(&grid, image)?;
+    }
+
     println!("silver: {}", silver(&grid));
-    println!("gold: {}", gold(&mut grid));
+    println!("gold: {}", gold(&grid));
 
     Ok(())
 }
@@ -1,6 +1,9 @@
 use std::io;
 
-use advent_2025::read_input_from_env;
+use advent_2025::{
+    parse::{Pattern, sections},
+    read_input_from_env,
+};
 
 #[derive(Debug, Clone, Copy)]
 struct Range {
@@ -20,24 +23,22 @@ impl Range {
 }
 
 fn parse(input: &str) -> (Vec<Range>, Vec<u64>) {
-    let mut lines = input.lines();
+    let [ranges, ids] = sections(input).collect_exact().unwrap();
+    let pattern = Pattern::new("{u64}-{u64}");
 
-    let ranges = lines
-        .by_ref()
-        .take_while(|line| !line.is_empty())
+    let ranges = ranges
+        .lines()
         .map(|line| {
-            let (start, end) = line.split_once('-').unwrap();
+            let (start, end) = pattern.parse(line.trim()).unwrap();
 
-            Range {
-                start: start.parse().unwrap(),
-                end: end.parse().unwrap(),
-            }
+            Range { start, end }
         })
         .collect();
 
-    // take_while above consumed the newline
-
-    let ids = lines.map(|line| line.parse().unwrap()).collect();
+    let ids = ids
+        .lines()
+        .map(|line| line.trim().parse().unwrap())
+        .collect();
 
     (ranges, ids)
 }
@@ -1,6 +1,11 @@
-use std::io;
+use std::{io, str::FromStr};
 
-use advent_2025::{Grid, read_input_from_env};
+use advent_2025::{
+    Grid,
+    answer::{Answer, Overflow, checked_add, checked_mul, checked_product, checked_sum},
+    parse::{Table, parse_u64},
+    read_input_from_env,
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Op {
@@ -8,20 +13,28 @@ enum Op {
     Multiply,
 }
 
-fn parse(input: &str) -> (Grid<u64>, Vec<Op>) {
-    let mut nums = Vec::new();
-    let mut ops = Vec::new();
+impl FromStr for Op {
+    type Err = ();
 
-    for thing in input.split_ascii_whitespace() {
-        match thing {
-            "+" => ops.push(Op::Add),
-            "*" => ops.push(Op::Multiply),
-            num => nums.push(num.parse().unwrap()),
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Op::Add),
+            "*" => Ok(Op::Multiply),
+            _ => Err(()),
         }
     }
+}
+
+fn parse(table: &Table) -> (Grid<u64>, Vec<Op>) {
+    let ops: Vec<Op> = table
+        .footer(0)
+        .expect("every problem should have an operator");
+    let nums = table
+        .tokens()
+        .map(|token| parse_u64(token.text.as_bytes()).unwrap())
+        .collect();
 
-    let height = nums.len() / ops.len();
-    let nums = Grid::from_vec_and_dimensions(nums, ops.len(), height);
+    let nums = Grid::from_vec_and_dimensions(nums, ops.len(), table.height());
 
     (nums, ops)
 }
@@ -32,103 +45,124 @@ enum Thing {
     Space,
 }
 
-fn parse_gold(input: &str) -> Grid<Thing> {
-    let mut width = 0;
-    let mut content = Vec::new();
-
-    // just gather everything into a grid
-    for (pos, chr) in input.char_indices() {
-        // find width of the grid
-        if width == 0 && (chr == '\n' || chr == '\r') {
-            width = pos + 1;
-        }
+fn parse_gold(table: &Table) -> Grid<Thing> {
+    table.to_grid().map(|&byte| match byte {
+        b'0'..=b'9' => Thing::Digit(byte - b'0'),
+        _ => Thing::Space,
+    })
+}
 
-        match chr {
-            digit if digit.is_ascii_digit() => {
-                content.push(Thing::Digit(digit.to_digit(10).unwrap() as u8))
-            }
-            space if space.is_ascii_whitespace() => content.push(Thing::Space),
-            // assumes that first character on the ops line is actually an op (i.e. not a space)
-            '+' | '*' => break,
-            _ => continue,
-        }
+/// Combine all numbers of a problem with its operator
+fn solve_problem<T: Answer>(op: &Op, nums: impl Iterator<Item = T>) -> Result<T, Overflow> {
+    match op {
+        Op::Add => checked_sum(nums, "sum of a problem"),
+        Op::Multiply => checked_product(nums, "product of a problem"),
     }
+}
 
-    let height = content.len() / width;
-    let grid = Grid::from_vec_and_dimensions(content, width, height);
+fn silver<T: Answer>(nums: &Grid<u64>, ops: &[Op]) -> Result<T, Overflow> {
+    let answers = ops
+        .iter()
+        .enumerate()
+        .map(|(col, op)| solve_problem(op, nums.iter_col(col).map(T::from)))
+        .collect::<Result<Vec<T>, _>>()?;
 
-    grid.clone_transposed()
+    checked_sum(answers, "grand total")
 }
 
-/// Get accumulator for given operation type
-fn accum_for_op(op: &Op) -> u64 {
-    match op {
-        Op::Add => 0,
-        Op::Multiply => 1,
-    }
+fn gold<T: Answer>(digits: &Grid<Thing>, ops: &[Op]) -> Result<T, Overflow> {
+    // problems are separated by columns without any digits,
+    // a column of zeros is still a number
+    let problems = digits.column_chunks(|thing| matches!(thing, Thing::Space));
+    assert_eq!(problems.len(), ops.len(), "every problem needs an operator");
+
+    let answers = problems
+        .iter()
+        .zip(ops)
+        .map(|(problem, op)| {
+            // each column is one number, read top to bottom
+            let nums = (0..problem.width())
+                .map(|col| {
+                    problem
+                        .iter_col(col)
+                        .try_fold(T::from(0), |num, thing| match thing {
+                            Thing::Digit(d) => checked_add(
+                                &checked_mul(&num, &T::from(10), "column number")?,
+                                &T::from(d as u64),
+                                "column number",
+                            ),
+                            Thing::Space => Ok(num),
+                        })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+
+            solve_problem(op, nums.into_iter())
+        })
+        .collect::<Result<Vec<T>, _>>()?;
+
+    checked_sum(answers, "grand total")
 }
 
-fn silver(nums: &Grid<u64>, ops: &[Op]) -> u64 {
-    let mut accumulator: Vec<u64> = ops.iter().map(accum_for_op).collect();
+fn main() -> io::Result<()> {
+    let input = read_input_from_env()?;
+    let table = Table::new(&input, 1);
+    let (nums, ops) = parse(&table);
 
-    for ((col, _row), num) in nums.iter_indexed() {
-        match ops[col] {
-            Op::Add => accumulator[col] += num,
-            Op::Multiply => accumulator[col] *= num,
-        }
-    }
+    // products of many numbers get large quickly
+    let silver: u128 = silver(&nums, &ops).map_err(io::Error::other)?;
+    println!("silver: {silver}");
 
-    accumulator.into_iter().sum()
+    let goldgrid = parse_gold(&table);
+    let gold: u128 = gold(&goldgrid, &ops).map_err(io::Error::other)?;
+    println!("gold: {gold}");
+
+    Ok(())
 }
 
-fn gold(digits: &Grid<Thing>, ops: &[Op]) -> u64 {
-    let mut total = 0;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut problem_i: usize = 0;
-    let mut subtotal = accum_for_op(&ops[problem_i]);
-    for row_i in 0..digits.height() {
-        let mut num: u64 = 0;
+    fn solve(input: &str) -> (u64, u64) {
+        let table = Table::new(input, 1);
+        let (nums, ops) = parse(&table);
 
-        // build the actual number digit by digit
-        for digit in digits.iter_row(row_i) {
-            if let Thing::Digit(d) = digit {
-                num = num * 10 + d as u64;
-            }
-        }
+        (
+            silver(&nums, &ops).unwrap(),
+            gold(&parse_gold(&table), &ops).unwrap(),
+        )
+    }
 
-        // if no digits were gathered, this is an empty line
-        // i.e. we moved to another problem
-        if num == 0 {
-            problem_i += 1;
-            total += subtotal;
+    #[test]
+    fn example() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
 
-            // check if we are at last problem
-            if let Some(next_op) = ops.get(problem_i) {
-                subtotal = accum_for_op(next_op);
-            } else {
-                break;
-            }
+        assert_eq!(solve(input), (4277556, 3263827));
+    }
 
-            continue;
-        }
+    #[test]
+    fn zero_digit_columns_are_numbers() {
+        // second column of the first problem is 00
+        let input = "10 4\n 0 5\n+  *\n";
 
-        match ops[problem_i] {
-            Op::Add => subtotal += num,
-            Op::Multiply => subtotal *= num,
-        }
+        assert_eq!(solve(input), (10 + 4 * 5, 1 + 45));
     }
 
-    total
-}
-
-fn main() -> io::Result<()> {
-    let input = read_input_from_env()?;
-    let (nums, ops) = parse(&input);
+    #[test]
+    fn operators_need_not_start_the_problem() {
+        let input = " 1 23\n45  6\n +  *\n";
 
-    println!("silver: {}", silver(&nums, &ops));
+        assert_eq!(solve(input), (1 + 45 + 23 * 6, 4 + 15 + 2 * 36));
+    }
 
-    let goldgrid = parse_gold(&input);
-    println!("gold: {}", gold(&goldgrid, &ops));
+    #[test]
+    fn reports_overflow() {
+        let input = "18446744073709551615\n                   2\n*\n";
+        let table = Table::new(input, 1);
+        let (nums, ops) = parse(&table);
 
-    Ok(())
+        let err = silver::<u64>(&nums, &ops).unwrap_err();
+        assert_eq!(err.context, "product of a problem");
+        assert_eq!(silver::<u128>(&nums, &ops), Ok(2 * u64::MAX as u128));
+    }
 }
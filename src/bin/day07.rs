@@ -1,10 +1,21 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::HashSet,
     fmt::{Display, Write},
-    io,
+    fs::File,
+    io::{self, BufWriter},
+    time::Duration,
 };
 
-use advent_2025::{Grid, read_input_from_env};
+use advent_2025::{
+    Grid, Params,
+    answer::{Answer, Overflow, checked_add},
+    grid::{
+        image::Image,
+        render::{Color, Style, animate},
+    },
+    memo::Memo,
+    read_input_from_env,
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Tile {
@@ -33,18 +44,13 @@ fn silver(grid: &Grid<Tile>) -> u64 {
     beam_cols.insert(start_col);
 
     let mut splits = 0;
-    for ((col, _row), tile) in grid.iter_indexed() {
-        match tile {
-            Tile::Splitter => {
-                if beam_cols.contains(&col) {
-                    splits += 1;
-                    beam_cols.remove(&col);
-
-                    beam_cols.insert(&col - 1);
-                    beam_cols.insert(&col + 1);
-                }
-            }
-            _ => continue,
+    for (col, _row) in grid.positions_by(|tile| matches!(tile, Tile::Splitter)) {
+        if beam_cols.contains(&col) {
+            splits += 1;
+            beam_cols.remove(&col);
+
+            beam_cols.insert(col - 1);
+            beam_cols.insert(col + 1);
         }
     }
 
@@ -53,60 +59,95 @@ fn silver(grid: &Grid<Tile>) -> u64 {
 
 /// Look for a splitter below some coordinate.
 /// Returns the same coordinate if the coordinate itself contains a splitter
-fn look_down_for_splitter(
-    grid: &Grid<Tile>,
-    (start_col, start_row): (usize, usize),
-) -> Option<(usize, usize)> {
-    for row in start_row..grid.height() {
-        if let Some(Tile::Splitter) = grid.at(start_col, row) {
-            return Some((start_col, row));
-        }
-    }
-
-    None
+fn look_down_for_splitter(grid: &Grid<Tile>, start: (usize, usize)) -> Option<(usize, usize)> {
+    grid.first_hit(start, (0, 1), |tile| matches!(tile, Tile::Splitter))
 }
 
-fn path_count(
+fn path_count<T: Answer>(
     grid: &Grid<Tile>,
-    memo: &mut HashMap<(usize, usize), u64>,
+    memo: &mut Memo<(usize, usize), T>,
     (col, row): (usize, usize),
-) -> u64 {
-    if let Some(&remembered) = memo.get(&(col, row)) {
-        return remembered;
-    }
-
-    let left_paths = if let Some(next) = look_down_for_splitter(grid, (col - 1, row)) {
-        path_count(grid, memo, next)
-    } else {
-        1 // base case, beam goes out of bounds
-    };
-
-    let right_paths = if let Some(next) = look_down_for_splitter(grid, (col + 1, row)) {
-        path_count(grid, memo, next)
-    } else {
-        1 // base case, beam goes out of bounds
-    };
-
-    // base case
-    memo.insert((col, row), left_paths + right_paths);
-    left_paths + right_paths
+) -> Result<T, Overflow> {
+    memo.try_get_or_compute((col, row), |memo| {
+        let left_paths = if let Some(next) = look_down_for_splitter(grid, (col - 1, row)) {
+            path_count(grid, memo, next)?
+        } else {
+            T::from(1) // base case, beam goes out of bounds
+        };
+
+        let right_paths = if let Some(next) = look_down_for_splitter(grid, (col + 1, row)) {
+            path_count(grid, memo, next)?
+        } else {
+            T::from(1) // base case, beam goes out of bounds
+        };
+
+        checked_add(&left_paths, &right_paths, "sum of path counts")
+    })
 }
 
-fn gold(grid: &Grid<Tile>) -> u64 {
+fn gold<T: Answer>(grid: &Grid<Tile>) -> Result<T, Overflow> {
     let (start_col, start_row) = grid
         .find_one_pos_by(|tile| matches!(tile, Tile::Start))
         .expect("failed to find start position");
 
-    let mut visited: HashMap<(usize, usize), u64> = HashMap::new();
-
     path_count(
         grid,
-        &mut visited,
+        &mut Memo::new(),
         look_down_for_splitter(grid, (start_col, start_row)).unwrap(),
     )
 }
 
+/// Animate beams moving down row by row, splitters which got hit are highlighted
+fn visualize(grid: &Grid<Tile>) -> io::Result<()> {
+    let (start_col, start_row) = grid
+        .find_one_pos_by(|tile| matches!(tile, Tile::Start))
+        .expect("failed to find start position");
+
+    let mut beam_cols = HashSet::from([start_col]);
+    let mut beams = Vec::new();
+    let mut hits = Vec::new();
+    let mut frames = Vec::new();
+
+    for row in start_row..grid.height() {
+        let split: Vec<usize> = beam_cols
+            .iter()
+            .copied()
+            .filter(|&col| matches!(grid[(col, row)], Tile::Splitter))
+            .collect();
+
+        for col in split {
+            beam_cols.remove(&col);
+            hits.push((col, row));
+
+            let sides = [col.checked_sub(1), Some(col + 1)];
+            beam_cols.extend(sides.into_iter().flatten().filter(|&c| c < grid.width()));
+        }
+        beams.extend(beam_cols.iter().map(|&col| (col, row)));
+
+        let frame = grid
+            .render(|_, _| Style::new().fg(Color::Blue))
+            .overlay(beams.iter().copied(), Style::new().fg(Color::Yellow).bold())
+            .overlay(hits.iter().copied(), Style::new().fg(Color::Red).bold())
+            .to_string();
+        frames.push(frame);
+    }
+
+    animate(&mut io::stdout(), frames, Duration::from_millis(100))
+}
+
+/// Save the manifold as a PPM image
+fn export_image(grid: &Grid<Tile>, path: &str) -> io::Result<()> {
+    let image = Image::from_grid(grid, 4, |_, tile| match tile {
+        Tile::Start => [40, 200, 40],
+        Tile::Empty => [20, 20, 20],
+        Tile::Splitter => [220, 60, 60],
+    });
+
+    image.write_ppm(BufWriter::new(File::create(path)?))
+}
+
 fn main() -> io::Result<()> {
+    let params = Params::from_env(&[("visualize", "false"), ("image", "")])?;
     let input = read_input_from_env()?;
     let grid = Grid::new(&input, |chr, _| match chr {
         'S' => Tile::Start,
@@ -115,8 +156,19 @@ fn main() -> io::Result<()> {
         _ => panic!("invalid tile"),
     });
 
+    let image = params.get_str("image");
+    if !image.is_empty() {
+        export_image(&grid, image)?;
+    }
+
+    if params.get("visualize")? {
+        visualize(&grid)?;
+    }
+
     println!("silver: {}", silver(&grid));
-    println!("gold: {}", gold(&grid));
+    // number of paths doubles with every splitter
+    let gold: u128 = gold(&grid).map_err(io::Error::other)?;
+    println!("gold: {gold}");
 
     Ok(())
 }
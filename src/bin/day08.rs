@@ -1,6 +1,6 @@
 use std::{collections::HashMap, io};
 
-use advent_2025::read_input_from_env;
+use advent_2025::{Params, parse::Scanner, read_input_from_env};
 
 #[derive(Debug, Clone, Copy)]
 struct Point {
@@ -36,7 +36,8 @@ fn brute_force_sorted_distances(points: &[Point]) -> Vec<(usize, usize, f64)> {
     out
 }
 
-fn solve<const GOLD: bool>(points: &[Point]) -> u64 {
+/// Silver only makes the `connections` shortest connections
+fn solve<const GOLD: bool>(points: &[Point], connections: usize) -> u64 {
     let distances = brute_force_sorted_distances(points);
 
     // mapping of point index => circuit id
@@ -46,7 +47,7 @@ fn solve<const GOLD: bool>(points: &[Point]) -> u64 {
     let distance_slice = if GOLD {
         &distances[..]
     } else {
-        &distances[..1000]
+        &distances[..connections]
     };
 
     for &(i, j, _dist) in distance_slice {
@@ -103,22 +104,32 @@ fn main() -> io::Result<()> {
     let input = read_input_from_env()?;
     let points = parse(&input);
 
-    println!("silver: {}", solve::<false>(&points));
-    println!("gold: {}", solve::<true>(&points));
+    // the example only makes 10 connections
+    let params = Params::from_env(&[("connections", "1000")])?;
+    let connections = params.get::<usize>("connections")?;
+    let pairs = points.len() * points.len().saturating_sub(1) / 2;
+    if connections > pairs {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("can't make {connections} connections between only {pairs} pairs"),
+        ));
+    }
+
+    println!("silver: {}", solve::<false>(&points, connections));
+    println!("gold: {}", solve::<true>(&points, connections));
 
     Ok(())
 }
 
 fn parse(input: &str) -> Vec<Point> {
-    input
-        .trim()
+    Scanner::new(input.trim().as_bytes())
         .lines()
-        .filter_map(|line| {
-            let mut parts = line.split(',');
-
-            let x = parts.next()?.parse().ok()?;
-            let y = parts.next()?.parse().ok()?;
-            let z = parts.next()?.parse().ok()?;
+        .filter_map(|mut line| {
+            let x = line.u64().ok()?;
+            line.expect(b',').ok()?;
+            let y = line.u64().ok()?;
+            line.expect(b',').ok()?;
+            let z = line.u64().ok()?;
 
             Some(Point { x, y, z })
         })
@@ -1,66 +1,83 @@
 use std::{collections::HashMap, io};
 
-use advent_2025::read_input_from_env;
+use advent_2025::{
+    Params,
+    answer::{Answer, Overflow, checked_product, checked_sum},
+    memo::Memo,
+    parse::Pattern,
+    read_input_from_env,
+};
+
+/// Names of the nodes both parts walk between
+struct Nodes<'a> {
+    /// silver starts here, i.e. `you`
+    start: &'a str,
+    /// both parts end here, i.e. `out`
+    end: &'a str,
+    /// gold starts here, i.e. `svr`
+    server: &'a str,
+    /// gold passes through both of these, in this order
+    fft: &'a str,
+    dac: &'a str,
+}
 
-fn silver(graph: &HashMap<&str, Vec<&str>>) -> u64 {
-    count_paths(graph, "you", "out")
+fn silver<T: Answer>(graph: &HashMap<&str, Vec<&str>>, nodes: &Nodes) -> Result<T, Overflow> {
+    count_paths(graph, nodes.start, nodes.end)
 }
 
-fn gold(graph: &HashMap<&str, Vec<&str>>) -> u64 {
+fn gold<T: Answer>(graph: &HashMap<&str, Vec<&str>>, nodes: &Nodes) -> Result<T, Overflow> {
     // since we're passing through fft and dac
     // we can multiply unique paths
     //   svr -> fft
     //   fft -> dac
     //   dac -> out
-    let svr_to_fft = count_paths(graph, "svr", "fft");
-    let fft_to_dac = count_paths(graph, "fft", "dac");
-    let dac_to_out = count_paths(graph, "dac", "out");
+    let svr_to_fft = count_paths(graph, nodes.server, nodes.fft)?;
+    let fft_to_dac = count_paths(graph, nodes.fft, nodes.dac)?;
+    let dac_to_out = count_paths(graph, nodes.dac, nodes.end)?;
 
     // in reality there's only fft -> dac path, but verify
-    debug_assert_eq!(count_paths(graph, "dac", "fft"), 0);
+    debug_assert_eq!(count_paths::<u64>(graph, nodes.dac, nodes.fft), Ok(0));
 
-    svr_to_fft * fft_to_dac * dac_to_out
+    checked_product(
+        [svr_to_fft, fft_to_dac, dac_to_out],
+        "product of path counts",
+    )
 }
 
 // https://stackoverflow.com/a/79295398
-fn recurse_paths<'a>(
-    graph: &HashMap<&'a str, Vec<&'a str>>,
-    memo: &mut HashMap<&'a str, u64>,
-    from: &'a str,
-    to: &'a str,
-) -> u64 {
-    // base case
-    if from == to {
-        return 1;
-    }
-
-    // return if we already know how many paths exists to target from this node
-    if let Some(remembered) = memo.get(from) {
-        return *remembered;
-    }
-
-    let mut path_count = 0;
-    for child in graph.get(from).unwrap_or(&vec![]) {
-        path_count += recurse_paths(graph, memo, child, to);
-    }
-
-    memo.insert(from, path_count);
-    path_count
-}
-
-fn count_paths(graph: &HashMap<&str, Vec<&str>>, from: &str, to: &str) -> u64 {
-    let mut memo = HashMap::new();
-
-    recurse_paths(graph, &mut memo, from, to)
+fn count_paths<T: Answer>(
+    graph: &HashMap<&str, Vec<&str>>,
+    from: &str,
+    to: &str,
+) -> Result<T, Overflow> {
+    // paths can be long, so walk them with an explicit stack instead of recursing
+    Memo::new().compute_iterative(
+        from,
+        |&node| match graph.get(node) {
+            // base case, no need to look past the target
+            _ if node == to => vec![],
+            Some(children) => children.clone(),
+            None => vec![],
+        },
+        |&node, child_paths| {
+            if node == to {
+                Ok(T::from(1))
+            } else {
+                checked_sum(child_paths, "sum of path counts")
+            }
+        },
+    )
 }
 
 fn parse(input: &str) -> HashMap<&str, Vec<&str>> {
+    let pattern = Pattern::new("{str}: {str}");
+
     input
         .trim()
         .lines()
         .filter_map(|line| {
-            let (ins, outs) = line.split_once(':')?;
-            let outs = outs.trim().split_ascii_whitespace().collect();
+            let (ins, outs): (&str, &str) = pattern.parse(line).ok()?;
+            let outs = outs.split_ascii_whitespace().collect();
 
             Some((ins, outs))
         })
@@ -107,7 +124,7 @@ fn to_graphviz(graph: &HashMap<&str, Vec<&str>>) -> String {
     out.push_str("dac [shape=diamond, style=filled, fillcolor=green]\n");
     out.push_str("fft [shape=diamond, style=filled, fillcolor=green]\n");
 
-    out.push_str("}");
+    out.push('}');
 
     out
 }
@@ -116,12 +133,29 @@ fn main() -> io::Result<()> {
     let input = read_input_from_env()?;
     let graph = parse(&input);
 
+    let params = Params::from_env(&[
+        ("start", "you"),
+        ("end", "out"),
+        ("server", "svr"),
+        ("fft", "fft"),
+        ("dac", "dac"),
+    ])?;
+    let nodes = Nodes {
+        start: params.get_str("start"),
+        end: params.get_str("end"),
+        server: params.get_str("server"),
+        fft: params.get_str("fft"),
+        dac: params.get_str("dac"),
+    };
+
     // let transpose = transpose_graph(&graph);
     // println!("{}", to_graphviz(&graph));
     // println!("{}", to_graphviz(&transpose));
 
-    println!("silver: {}", silver(&graph));
-    println!("gold: {}", gold(&graph));
+    let silver: u128 = silver(&graph, &nodes).map_err(io::Error::other)?;
+    println!("silver: {silver}");
+    let gold: u128 = gold(&graph, &nodes).map_err(io::Error::other)?;
+    println!("gold: {gold}");
 
     Ok(())
 }
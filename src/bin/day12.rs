@@ -1,6 +1,9 @@
 use std::io;
 
-use advent_2025::read_input_from_env;
+use advent_2025::{
+    parse::{Pattern, sections},
+    read_input_from_env,
+};
 
 #[derive(Debug)]
 struct Problem {
@@ -10,31 +13,18 @@ struct Problem {
 }
 
 fn parse(input: &str) -> ([usize; 6], Vec<Problem>) {
-    let mut lines = input.lines();
-
-    // scuffed parsing '#'s in each grid
-    let mut sizes = [0; 6];
-    let mut grid_index = 0;
-    let mut total_hashtags = 0;
-    for gridline in lines.by_ref().take(30) {
-        // assumes that last line is empty
-        if gridline.is_empty() {
-            sizes[grid_index] = total_hashtags;
-            total_hashtags = 0;
-            grid_index += 1;
-        }
+    // six shapes followed by the problems
+    let [shapes @ .., regions] = sections(input).collect_exact::<7>().unwrap();
 
-        total_hashtags += gridline.chars().filter(|&chr| chr == '#').count();
-    }
+    // count '#'s in each shape
+    let sizes = shapes.map(|shape| shape.chars().filter(|&chr| chr == '#').count());
 
     // rest of the lines are problems
-    let problems = lines
+    let pattern = Pattern::new("{usize}x{usize}: {str}");
+    let problems = regions
+        .lines()
         .filter_map(|line| {
-            let (size, counts) = line.split_once(':')?;
-
-            let (width, height) = size.split_once('x')?;
-            let width = width.parse::<usize>().ok()?;
-            let height = height.parse::<usize>().ok()?;
+            let (width, height, counts): (usize, usize, &str) = pattern.parse(line).ok()?;
 
             let mut out = Problem {
                 width,
@@ -42,7 +32,7 @@ fn parse(input: &str) -> ([usize; 6], Vec<Problem>) {
                 counts: [0; 6],
             };
 
-            for (i, count) in counts.trim().split_ascii_whitespace().enumerate() {
+            for (i, count) in counts.split_ascii_whitespace().enumerate() {
                 out.counts[i] = count.parse().ok()?;
             }
 
@@ -0,0 +1,172 @@
+//! Cycle detection for iterated simulations
+//!
+//! Uses Brent's algorithm, so only `Clone + PartialEq` is needed
+//! and at most two states are kept in memory.
+//! <https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm>
+
+/// Shape of the sequence `initial, step(initial), step(step(initial)), ...`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of states before the cycle starts
+    pub prefix: usize,
+    /// Length of the cycle
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Smallest number of steps which reaches the same state as `n` steps
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Finds where the sequence of states starts repeating
+///
+/// Never returns if the sequence doesn't repeat.
+pub fn find_cycle<S: Clone + PartialEq>(initial: &S, step: impl Fn(&S) -> S) -> Cycle {
+    // find period by moving hare ahead in powers of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // find start of the cycle by keeping tortoise and hare `period` apart
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+/// State after `n` steps, skipping over repeated cycles
+///
+/// Steps one at a time while looking for a cycle, so sequences which don't
+/// repeat within `n` steps work too.
+pub fn state_after<S: Clone + PartialEq>(initial: S, n: usize, step: impl Fn(&S) -> S) -> S {
+    // same period search as in find_cycle, but giving up after n steps
+    let mut power = 1;
+    let mut period = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+
+    for done in 1..=n {
+        hare = step(&hare);
+        period += 1;
+
+        if tortoise == hare {
+            // from here on states repeat every `period` steps
+            let remaining = (n - done) % period;
+            return (0..remaining).fold(hare, |state, _| step(&state));
+        }
+
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+    }
+
+    hare
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_prefix_and_period() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 2 ...
+        let step = |&n: &u32| if n == 6 { 2 } else { n + 1 };
+
+        assert_eq!(
+            find_cycle(&0, step),
+            Cycle {
+                prefix: 2,
+                period: 5
+            }
+        );
+    }
+
+    #[test]
+    fn pure_cycle_and_fixpoint() {
+        assert_eq!(
+            find_cycle(&0, |&n: &u32| (n + 1) % 7),
+            Cycle {
+                prefix: 0,
+                period: 7
+            }
+        );
+        assert_eq!(
+            find_cycle(&5, |&n: &u32| n.saturating_sub(1)),
+            Cycle {
+                prefix: 5,
+                period: 1
+            }
+        );
+    }
+
+    #[test]
+    fn works_with_grids() {
+        let grid = crate::Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+
+        assert_eq!(
+            find_cycle(&grid, |grid| grid.rotate_cw()),
+            Cycle {
+                prefix: 0,
+                period: 4
+            }
+        );
+    }
+
+    #[test]
+    fn jumps_ahead() {
+        let step = |&n: &u64| (n * n + 1) % 255;
+
+        let mut naive = 3;
+        for n in 0..1000 {
+            assert_eq!(state_after(3, n, step), naive);
+            naive = step(&naive);
+        }
+
+        let cycle = find_cycle(&3, step);
+        let far = 1_000_000_000_000;
+        assert_eq!(
+            state_after(3, far, step),
+            state_after(3, far + cycle.period, step)
+        );
+    }
+
+    #[test]
+    fn steps_sequences_without_cycle() {
+        let step = |&n: &u64| n + 1;
+
+        assert_eq!(state_after(0, 0, step), 0);
+        assert_eq!(state_after(0, 10, step), 10);
+        assert_eq!(state_after(5, 1, |&n: &u64| n.saturating_sub(1)), 4);
+        assert_eq!(state_after(5, 100, |&n: &u64| n.saturating_sub(1)), 0);
+    }
+}
@@ -1,15 +1,31 @@
 #![allow(dead_code)]
 
-use std::fmt::Display;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    ops::{Index, IndexMut},
+};
 
-#[derive(Debug, Clone)]
+mod bits;
+pub mod image;
+mod ray;
+pub mod render;
+mod sparse;
+mod view;
+pub use bits::BitGrid;
+pub use ray::Ray;
+pub use sparse::{Bounds, SparseEntry, SparseEntryMut, SparseGrid};
+pub use view::GridView;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Grid<T> {
     content: Vec<T>,
     width: usize,
     height: usize,
 }
 
-impl<T: Copy> Grid<T> {
+impl<T> Grid<T> {
     pub fn new(content: &str, transform: impl Fn(char, (usize, usize)) -> T) -> Self {
         // count number of lines (ignoring empty lines) and length with of first line
         let height = content.lines().filter(|line| !line.is_empty()).count();
@@ -52,14 +68,20 @@ impl<T: Copy> Grid<T> {
         self.content.as_mut_slice()
     }
 
-    pub fn at(&self, col: usize, row: usize) -> Option<T> {
-        let index = col + row * self.width;
-        self.content.get(index).copied()
+    pub fn at(&self, col: usize, row: usize) -> Option<&T> {
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+
+        self.content.get(col + row * self.width)
     }
 
     pub fn at_mut(&mut self, col: usize, row: usize) -> Option<&mut T> {
-        let index = col + row * self.width;
-        self.content.get_mut(index)
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+
+        self.content.get_mut(col + row * self.width)
     }
 
     pub fn entry(&self, col: usize, row: usize) -> GridEntry<'_, T> {
@@ -67,6 +89,7 @@ impl<T: Copy> Grid<T> {
             grid: self,
             col,
             row,
+            addressing: Addressing::Bounded,
         }
     }
 
@@ -75,6 +98,7 @@ impl<T: Copy> Grid<T> {
             grid: self,
             col,
             row,
+            addressing: Addressing::Bounded,
         }
     }
 
@@ -94,11 +118,57 @@ impl<T: Copy> Grid<T> {
         })
     }
 
+    pub fn iter_indexed_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width();
+        self.content.iter_mut().enumerate().map(move |(i, c)| {
+            let pos = (i % width, i / width);
+            (pos, c)
+        })
+    }
+
+    /// Creates a new grid of the same size by transforming every item
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            content: self.content.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Like [`Self::map`], but also gives the position of each item
+    pub fn map_indexed<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            content: self.iter_indexed().map(|(pos, t)| f(pos, t)).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Combines two grids of the same size item by item
+    ///
+    /// Panics if dimensions don't match
+    pub fn zip_with<U, V>(&self, other: &Grid<U>, mut f: impl FnMut(&T, &U) -> V) -> Grid<V> {
+        if (self.width, self.height) != (other.width, other.height) {
+            panic!("grid dimensions don't match");
+        }
+
+        Grid {
+            content: self
+                .content
+                .iter()
+                .zip(&other.content)
+                .map(|(t, u)| f(t, u))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
     /// Find position of one item by some predicate.
     ///
     /// Useful for finding starting positions etc..
-    pub fn find_one_pos_by(&self, pred: impl Fn(T) -> bool) -> Option<(usize, usize)> {
-        for (pos, &t) in self.iter_indexed() {
+    pub fn find_one_pos_by(&self, pred: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        for (pos, t) in self.iter_indexed() {
             if pred(t) {
                 return Some(pos);
             }
@@ -106,22 +176,191 @@ impl<T: Copy> Grid<T> {
         None
     }
 
-    /// Get an iterator over given row
+    /// Positions of all items matching the predicate, row by row
+    pub fn positions_by(&self, pred: impl Fn(&T) -> bool) -> impl Iterator<Item = (usize, usize)> {
+        self.iter_indexed()
+            .filter(move |(_, t)| pred(t))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn count_by(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.content.iter().filter(|t| pred(t)).count()
+    }
+
+    /// Smallest view containing every item matching the predicate
+    ///
+    /// Returns `None` if nothing matches
+    pub fn bounding_box_by(&self, pred: impl Fn(&T) -> bool) -> Option<GridView<'_, T>> {
+        let mut positions = self.positions_by(pred);
+        let (first_col, first_row) = positions.next()?;
+
+        let (min_col, max_col, max_row) = positions.fold(
+            (first_col, first_col, first_row),
+            |(min_col, max_col, _), (col, row)| (min_col.min(col), max_col.max(col), row),
+        );
+
+        // positions come row by row, so the first one has the smallest row
+        self.view(
+            min_col,
+            first_row,
+            max_col - min_col + 1,
+            max_row - first_row + 1,
+        )
+    }
+
+    /// Number of occurrences of each distinct item
+    pub fn histogram(&self) -> HashMap<&T, usize>
+    where
+        T: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for t in &self.content {
+            *counts.entry(t).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Get an iterator over references to given row
     ///
     /// Returns an empty iterator if row is out of bounds
-    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = T> {
+    pub fn iter_row_ref(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.get_row(row).unwrap_or_default().iter()
+    }
+
+    fn get_row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.height {
+            return None;
+        }
+
+        Some(&self.content[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Borrow given row as a slice
+    ///
+    /// Panics if row is out of bounds
+    pub fn row(&self, row: usize) -> &[T] {
+        self.get_row(row).expect("row out of bounds")
+    }
+
+    /// Panics if row is out of bounds
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        if row >= self.height {
+            panic!("row out of bounds");
+        }
+
+        &mut self.content[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Iterate over rows as slices, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Get an iterator over references to given column
+    ///
+    /// Returns an empty iterator if column is out of bounds
+    pub fn iter_col_ref(&self, col: usize) -> impl Iterator<Item = &T> {
+        let height = if col < self.width { self.height } else { 0 };
+
+        // step_by panics on 0, but a grid without columns has nothing to step over anyway
         self.content
             .iter()
-            .skip(row * self.width)
-            .take(self.width)
-            .copied()
+            .skip(col)
+            .step_by(self.width.max(1))
+            .take(height)
     }
 
-    /// Get an iterator over given column
+    /// Borrow a `width` x `height` rectangle starting at given position
     ///
-    /// Returns an empty iterator if column is out of bounds
-    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = T> {
-        self.content.iter().skip(col).step_by(self.width).copied()
+    /// Returns `None` if the rectangle doesn't fit inside the grid
+    pub fn view(
+        &self,
+        col: usize,
+        row: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<GridView<'_, T>> {
+        GridView::new(self, (col, row), width, height)
+    }
+
+    /// View of the whole grid
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(self, (0, 0), self.width, self.height).unwrap()
+    }
+
+    /// Iterate over all `width` x `height` views, row by row
+    ///
+    /// Yields nothing if the window is larger than the grid
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = GridView<'_, T>> {
+        let cols = (self.width + 1).saturating_sub(width);
+        let rows = (self.height + 1).saturating_sub(height);
+
+        (0..rows).flat_map(move |row| {
+            (0..cols).filter_map(move |col| self.view(col, row, width, height))
+        })
+    }
+
+    /// Split grid into full-height views separated by blank columns
+    ///
+    /// A column is blank if `is_blank` holds for every cell in it.
+    /// Blank columns themselves are not part of any chunk.
+    pub fn column_chunks(&self, is_blank: impl Fn(&T) -> bool) -> Vec<GridView<'_, T>> {
+        let mut chunks = Vec::new();
+        let mut start = None;
+
+        for col in 0..=self.width {
+            let blank = col == self.width || self.iter_col_ref(col).all(&is_blank);
+
+            match (blank, start) {
+                (false, None) => start = Some(col),
+                (true, Some(first)) => {
+                    chunks.extend(self.view(first, 0, col - first, self.height));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        chunks
+    }
+
+    /// Removes a row and returns its contents
+    ///
+    /// Panics if `row >= height`
+    pub fn remove_row(&mut self, row: usize) -> Vec<T> {
+        if row >= self.height {
+            panic!("row index out of bounds");
+        }
+
+        let start = row * self.width;
+        let removed = self.content.drain(start..start + self.width).collect();
+        self.height -= 1;
+
+        removed
+    }
+
+    /// Removes a column and returns its contents
+    ///
+    /// Panics if `col >= width`
+    pub fn remove_col(&mut self, col: usize) -> Vec<T> {
+        if col >= self.width {
+            panic!("column index out of bounds");
+        }
+
+        let mut removed = Vec::with_capacity(self.height);
+        let mut kept = Vec::with_capacity(self.content.len() - self.height);
+        for (i, t) in std::mem::take(&mut self.content).into_iter().enumerate() {
+            if i % self.width == col {
+                removed.push(t);
+            } else {
+                kept.push(t);
+            }
+        }
+
+        self.content = kept;
+        self.width -= 1;
+
+        removed
     }
 
     /// Transposes itself in-place if the grid is square
@@ -148,6 +387,154 @@ impl<T: Copy> Grid<T> {
         }
     }
 
+    /// Rotates itself 90 degrees clockwise in-place if the grid is square
+    ///
+    /// Panics if the grid is not square
+    pub fn rotate_cw_square(&mut self) {
+        self.transpose_square();
+        self.flip_horizontal();
+    }
+
+    /// Rotates itself 90 degrees counter-clockwise in-place if the grid is square
+    ///
+    /// Panics if the grid is not square
+    pub fn rotate_ccw_square(&mut self) {
+        self.transpose_square();
+        self.flip_vertical();
+    }
+
+    /// Transposes the grid, consuming it
+    ///
+    /// Unlike [`Self::clone_transposed`] this works for any `T`
+    pub fn into_transposed(self) -> Self {
+        let (width, height) = (self.width, self.height);
+        let mut slots: Vec<Option<T>> = self.content.into_iter().map(Some).collect();
+
+        // walk destination in order, i.e. columns of the original grid
+        let content = (0..width * height)
+            .map(|dst| {
+                let (row, col) = (dst % height, dst / height);
+                slots[row * width + col].take().unwrap()
+            })
+            .collect();
+
+        Grid {
+            content,
+            width: height,
+            height: width,
+        }
+    }
+
+    /// Mirrors itself left to right, i.e. reverses every row
+    pub fn flip_horizontal(&mut self) {
+        if self.width == 0 {
+            return;
+        }
+
+        for row in self.content.chunks_exact_mut(self.width) {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors itself top to bottom, i.e. reverses order of rows
+    pub fn flip_vertical(&mut self) {
+        for row in 0..self.height / 2 {
+            let other = self.height - 1 - row;
+            let (top, bottom) = self.content.split_at_mut(other * self.width);
+
+            top[row * self.width..(row + 1) * self.width]
+                .swap_with_slice(&mut bottom[..self.width]);
+        }
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Get an iterator over given row
+    ///
+    /// Returns an empty iterator if row is out of bounds
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = T> {
+        self.iter_row_ref(row).copied()
+    }
+
+    /// Get an iterator over given column
+    ///
+    /// Returns an empty iterator if column is out of bounds
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = T> {
+        self.iter_col_ref(col).copied()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Sets every item to `value`
+    pub fn fill(&mut self, value: T) {
+        self.content.fill(value);
+    }
+
+    /// Surrounds the grid with a border `n` cells thick
+    ///
+    /// Useful for sentinels, everything moves `n` cells right and down.
+    pub fn pad(&mut self, n: usize, value: T) {
+        let width = self.width + 2 * n;
+        let height = self.height + 2 * n;
+
+        let mut content = Vec::with_capacity(width * height);
+        content.resize(n * width, value.clone());
+        for row in self.content.chunks_exact(self.width.max(1)) {
+            content.extend(std::iter::repeat_n(value.clone(), n));
+            content.extend_from_slice(row);
+            content.extend(std::iter::repeat_n(value.clone(), n));
+        }
+        content.resize(width * height, value);
+
+        *self = Self::from_vec_and_dimensions(content, width, height);
+    }
+
+    /// Inserts a new row filled with `value` before `row`
+    ///
+    /// Panics if `row > height`
+    pub fn insert_row(&mut self, row: usize, value: T) {
+        if row > self.height {
+            panic!("row index out of bounds");
+        }
+
+        let index = row * self.width;
+        self.content
+            .splice(index..index, std::iter::repeat_n(value, self.width));
+        self.height += 1;
+    }
+
+    /// Inserts a new column filled with `value` before `col`
+    ///
+    /// Panics if `col > width`
+    pub fn insert_col(&mut self, col: usize, value: T) {
+        if col > self.width {
+            panic!("column index out of bounds");
+        }
+
+        let mut content = Vec::with_capacity((self.width + 1) * self.height);
+        for row in 0..self.height {
+            let start = row * self.width;
+            content.extend_from_slice(&self.content[start..start + col]);
+            content.push(value.clone());
+            content.extend_from_slice(&self.content[start + col..start + self.width]);
+        }
+
+        self.content = content;
+        self.width += 1;
+    }
+
+    /// Shrinks the grid to a `width` x `height` rectangle starting at given position
+    ///
+    /// Panics if the rectangle doesn't fit inside the grid
+    pub fn crop(&mut self, col: usize, row: usize, width: usize, height: usize) {
+        let cropped = self
+            .view(col, row, width, height)
+            .expect("crop rectangle out of bounds")
+            .to_grid();
+
+        *self = cropped;
+    }
+
     /// Creates a transposed copy of the grid
     ///
     /// If your grid is a square,
@@ -163,7 +550,7 @@ impl<T: Copy> Grid<T> {
                 let src = row * self.width + col;
                 let dst = col * self.height + row;
 
-                new_content[dst] = self.content[src];
+                new_content[dst] = self.content[src].clone();
             }
         }
 
@@ -173,6 +560,75 @@ impl<T: Copy> Grid<T> {
             height: self.width,
         }
     }
+
+    /// Creates a copy of the grid rotated 90 degrees clockwise
+    ///
+    /// If your grid is a square,
+    /// you may want to use [`Self::rotate_cw_square`] instead
+    pub fn rotate_cw(&self) -> Self {
+        let mut rotated = self.clone_transposed();
+        rotated.flip_horizontal();
+        rotated
+    }
+
+    /// Creates a copy of the grid rotated 90 degrees counter-clockwise
+    ///
+    /// If your grid is a square,
+    /// you may want to use [`Self::rotate_ccw_square`] instead
+    pub fn rotate_ccw(&self) -> Self {
+        let mut rotated = self.clone_transposed();
+        rotated.flip_vertical();
+        rotated
+    }
+
+    /// All unique rotations and reflections of the grid,
+    /// starting with the grid itself
+    ///
+    /// There are at most eight, symmetric grids produce fewer.
+    pub fn orientations(&self) -> Vec<Self>
+    where
+        T: PartialEq,
+    {
+        let mut out: Vec<Self> = Vec::with_capacity(8);
+        let mut current = self.clone();
+
+        for _ in 0..2 {
+            for _ in 0..4 {
+                if !out.contains(&current) {
+                    out.push(current.clone());
+                }
+
+                current = current.rotate_cw();
+            }
+
+            current.flip_horizontal();
+        }
+
+        out
+    }
+}
+
+/// Indexing with `(col, row)`, panics if position is out of bounds
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (col, row): (usize, usize)) -> &Self::Output {
+        if col >= self.width || row >= self.height {
+            panic!("position out of bounds");
+        }
+
+        &self.content[col + row * self.width]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut Self::Output {
+        if col >= self.width || row >= self.height {
+            panic!("position out of bounds");
+        }
+
+        &mut self.content[col + row * self.width]
+    }
 }
 
 impl<T> Display for Grid<T>
@@ -188,7 +644,7 @@ where
 
             if row + 1 != self.height {
                 // print newline if this isn't the last row
-                write!(f, "\n")?;
+                writeln!(f)?;
             }
         }
 
@@ -196,41 +652,94 @@ where
     }
 }
 
+/// What happens to positions past the edges of a grid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Addressing {
+    /// Positions outside of the grid don't exist
+    #[default]
+    Bounded,
+    /// Leaving on one edge enters on the opposite one, like on a torus
+    Wrapping,
+    /// Positions outside of the grid stick to the nearest edge cell
+    Clamped,
+}
+
+impl Addressing {
+    /// Moves `(col, row)` by the offset within a grid of given size
+    ///
+    /// Returns `None` if the resulting position can't be addressed.
+    pub fn resolve(
+        self,
+        (col, row): (usize, usize),
+        (col_offset, row_offset): (isize, isize),
+        (width, height): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        match self {
+            Addressing::Bounded => {
+                let true_col = col.checked_add_signed(col_offset)?;
+                let true_row = row.checked_add_signed(row_offset)?;
+
+                (true_col < width && true_row < height).then_some((true_col, true_row))
+            }
+            Addressing::Wrapping => {
+                let wrap = |pos: usize, offset: isize, len: usize| {
+                    let len = len as isize;
+                    // reduce both first so the sum can't overflow
+                    ((pos as isize).rem_euclid(len) + offset.rem_euclid(len)).rem_euclid(len)
+                        as usize
+                };
+
+                Some((wrap(col, col_offset, width), wrap(row, row_offset, height)))
+            }
+            Addressing::Clamped => {
+                let clamp = |pos: usize, offset: isize, len: usize| {
+                    pos.saturating_add_signed(offset).min(len - 1)
+                };
+
+                Some((
+                    clamp(col, col_offset, width),
+                    clamp(row, row_offset, height),
+                ))
+            }
+        }
+    }
+}
+
 /// Helper for working with offsets
 #[derive(Debug)]
 pub struct GridEntry<'a, T> {
     grid: &'a Grid<T>,
     col: usize,
     row: usize,
+    addressing: Addressing,
 }
 
-// todo: get rid of Copy bound and make callers rely on .copied() ?
-impl<'a, T: Copy> GridEntry<'a, T> {
-    pub fn at_offset(&self, col_offset: isize, row_offset: isize) -> Option<T> {
+impl<'a, T> GridEntry<'a, T> {
+    /// Changes how offsets past the edges are resolved, the default is [`Addressing::Bounded`]
+    pub fn with_addressing(mut self, addressing: Addressing) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
+    pub fn at_offset(&self, col_offset: isize, row_offset: isize) -> Option<&'a T> {
         self.offset(col_offset, row_offset).map(|thing| thing.0)
     }
 
     /// Returns item at offset and its true column and row index if valid
-    pub fn offset(&self, col_offset: isize, row_offset: isize) -> Option<(T, usize, usize)> {
-        let Some(true_col) = self.col.checked_add_signed(col_offset) else {
-            return None;
-        };
-        let Some(true_row) = self.row.checked_add_signed(row_offset) else {
-            return None;
-        };
+    pub fn offset(&self, col_offset: isize, row_offset: isize) -> Option<(&'a T, usize, usize)> {
+        let (true_col, true_row) = self.addressing.resolve(
+            (self.col, self.row),
+            (col_offset, row_offset),
+            (self.grid.width(), self.grid.height()),
+        )?;
 
-        if true_col >= self.grid.width() {
-            return None;
-        }
-        if true_row >= self.grid.height() {
-            return None;
-        }
-
-        if let Some(thing) = self.grid.at(true_col, true_row) {
-            Some((thing, true_col, true_row))
-        } else {
-            None
-        }
+        self.grid
+            .at(true_col, true_row)
+            .map(|thing| (thing, true_col, true_row))
     }
 }
 
@@ -241,6 +750,7 @@ impl<'a, T> From<GridEntryMut<'a, T>> for GridEntry<'a, T> {
             grid: value.grid,
             col: value.col,
             row: value.row,
+            addressing: value.addressing,
         }
     }
 }
@@ -249,9 +759,16 @@ pub struct GridEntryMut<'a, T> {
     grid: &'a mut Grid<T>,
     col: usize,
     row: usize,
+    addressing: Addressing,
 }
 
-impl<T: Copy> GridEntryMut<'_, T> {
+impl<T> GridEntryMut<'_, T> {
+    /// See [`GridEntry::with_addressing`]
+    pub fn with_addressing(mut self, addressing: Addressing) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
     pub fn at_offset_mut(&mut self, col_offset: isize, row_offset: isize) -> Option<&mut T> {
         self.offset_mut(col_offset, row_offset).map(|thing| thing.0)
     }
@@ -262,25 +779,15 @@ impl<T: Copy> GridEntryMut<'_, T> {
         col_offset: isize,
         row_offset: isize,
     ) -> Option<(&mut T, usize, usize)> {
-        let Some(true_col) = self.col.checked_add_signed(col_offset) else {
-            return None;
-        };
-        let Some(true_row) = self.row.checked_add_signed(row_offset) else {
-            return None;
-        };
-
-        if true_col >= self.grid.width() {
-            return None;
-        }
-        if true_row >= self.grid.height() {
-            return None;
-        }
+        let (true_col, true_row) = self.addressing.resolve(
+            (self.col, self.row),
+            (col_offset, row_offset),
+            (self.grid.width(), self.grid.height()),
+        )?;
 
-        if let Some(thing) = self.grid.at_mut(true_col, true_row) {
-            Some((thing, true_col, true_row))
-        } else {
-            None
-        }
+        self.grid
+            .at_mut(true_col, true_row)
+            .map(|thing| (thing, true_col, true_row))
     }
 }
 
@@ -315,6 +822,269 @@ mod tests {
         assert_eq!(transposed.height(), 1);
     }
 
+    #[test]
+    fn rotates() {
+        // 1 2 3
+        // 4 5 6
+        let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+
+        let cw = grid.rotate_cw();
+        assert_eq!((cw.width(), cw.height()), (2, 3));
+        assert_eq!(cw.content(), &[4, 1, 5, 2, 6, 3]);
+
+        let ccw = grid.rotate_ccw();
+        assert_eq!(ccw.content(), &[3, 6, 2, 5, 1, 4]);
+        assert_eq!(ccw.rotate_cw().content(), grid.content());
+    }
+
+    #[test]
+    fn rotates_square() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+
+        grid.rotate_cw_square();
+        assert_eq!(grid.content(), &[7, 4, 1, 8, 5, 2, 9, 6, 3]);
+
+        grid.rotate_ccw_square();
+        assert_eq!(grid.content(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn flips() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 2, 3);
+
+        grid.flip_horizontal();
+        assert_eq!(grid.content(), &[2, 1, 4, 3, 6, 5]);
+
+        grid.flip_vertical();
+        assert_eq!(grid.content(), &[6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn unique_orientations() {
+        let asymmetric = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4], 2, 2);
+        assert_eq!(asymmetric.orientations().len(), 8);
+
+        let mirrored = Grid::from_vec_and_dimensions(vec![1, 1, 2, 2], 2, 2);
+        assert_eq!(mirrored.orientations().len(), 4);
+
+        let uniform = Grid::from_vec_and_dimensions(vec![0; 4], 2, 2);
+        assert_eq!(uniform.orientations().len(), 1);
+    }
+
+    #[test]
+    fn views_use_local_coordinates() {
+        // 1 2 3
+        // 4 5 6
+        // 7 8 9
+        let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+        let view = grid.view(1, 1, 2, 2).unwrap();
+
+        assert_eq!(view.at(0, 0), Some(&5));
+        assert_eq!(view.at(1, 1), Some(&9));
+        assert_eq!(view.at(2, 0), None);
+        assert!(view.iter_row(1).eq([8, 9]));
+        assert!(view.iter_col(0).eq([5, 8]));
+        assert_eq!(view.to_grid().content(), &[5, 6, 8, 9]);
+
+        assert!(grid.view(2, 2, 2, 1).is_none());
+        assert!(grid.view(1, 0, usize::MAX, 1).is_none());
+        assert!(view.view(0, 1, 1, usize::MAX).is_none());
+    }
+
+    #[test]
+    fn iterates_windows() {
+        let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+        let sums: Vec<i32> = grid
+            .windows(2, 2)
+            .map(|view| view.iter_indexed().map(|(_, t)| t).sum())
+            .collect();
+
+        assert_eq!(sums, [12, 16]);
+        assert_eq!(grid.windows(4, 1).count(), 0);
+    }
+
+    #[test]
+    fn splits_column_chunks() {
+        // 1 0 2 3 0
+        // 4 0 0 5 0
+        let grid = Grid::from_vec_and_dimensions(vec![1, 0, 2, 3, 0, 4, 0, 0, 5, 0], 5, 2);
+        let chunks = grid.column_chunks(|&t| t == 0);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].origin(), (0, 0));
+        assert_eq!(chunks[0].width(), 1);
+        assert_eq!(chunks[1].origin(), (2, 0));
+        assert_eq!(chunks[1].to_grid().content(), &[2, 3, 0, 5]);
+    }
+
+    #[test]
+    fn pads() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2], 2, 1);
+        grid.pad(1, 0);
+
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.content(), &[0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn inserts_and_removes_rows_and_cols() {
+        // 1 2
+        // 3 4
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4], 2, 2);
+
+        grid.insert_row(1, 0);
+        assert_eq!(grid.content(), &[1, 2, 0, 0, 3, 4]);
+
+        grid.insert_col(2, 9);
+        assert_eq!(grid.content(), &[1, 2, 9, 0, 0, 9, 3, 4, 9]);
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+
+        assert_eq!(grid.remove_col(0), [1, 0, 3]);
+        assert_eq!(grid.remove_row(1), [0, 9]);
+        assert_eq!(grid.content(), &[2, 9, 4, 9]);
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+    }
+
+    #[test]
+    fn crops() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+        grid.crop(1, 0, 2, 2);
+
+        assert_eq!(grid.content(), &[2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn holds_owned_data() {
+        let content = ["a", "b", "c", "d", "e", "f"].map(String::from).to_vec();
+        let mut grid = Grid::from_vec_and_dimensions(content, 3, 2);
+
+        grid.at_mut(0, 0).unwrap().push('!');
+        assert_eq!(grid.at(0, 0).map(String::as_str), Some("a!"));
+        assert_eq!(grid.entry(1, 1).at_offset(-1, -1).unwrap(), "a!");
+        assert!(grid.iter_col_ref(2).eq(["c", "f"]));
+
+        let grid = grid.into_transposed();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert!(grid.iter_row_ref(1).eq(["b", "e"]));
+        assert_eq!(grid.find_one_pos_by(|s| s == "f"), Some((1, 2)));
+    }
+
+    #[test]
+    fn maps_and_mutates() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4], 2, 2);
+
+        for ((col, row), t) in grid.iter_indexed_mut() {
+            *t += col * 10 + row * 100;
+        }
+        assert_eq!(grid.content(), &[1, 12, 103, 114]);
+
+        let odd = grid.map(|t| t % 2 == 1);
+        assert_eq!(odd.content(), &[true, false, true, false]);
+
+        let diagonal = grid.map_indexed(|(col, row), _| col == row);
+        assert_eq!(diagonal.content(), &[true, false, false, true]);
+
+        let kept = grid.zip_with(&diagonal, |&t, &keep| if keep { t } else { 0 });
+        assert_eq!(kept.content(), &[1, 0, 0, 114]);
+
+        grid.fill(7);
+        assert_eq!(grid.content(), &[7; 4]);
+    }
+
+    #[test]
+    fn grids_are_hashable() {
+        use std::collections::HashSet;
+
+        let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4], 2, 2);
+        let tall = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4], 1, 4);
+
+        let seen: HashSet<_> = [grid.clone(), grid.rotate_cw(), tall.clone(), grid.clone()]
+            .into_iter()
+            .collect();
+
+        assert_eq!(seen.len(), 3);
+        assert_ne!(grid, tall);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn entries_honour_addressing() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+        let entry = grid.entry(0, 0);
+
+        assert_eq!(entry.at_offset(-1, 0), None);
+
+        let entry = entry.with_addressing(Addressing::Wrapping);
+        assert_eq!(entry.offset(-1, 0), Some((&3, 2, 0)));
+        assert_eq!(entry.offset(4, -3), Some((&5, 1, 1)));
+        assert_eq!(entry.at_offset(isize::MIN, isize::MAX), Some(&5));
+
+        let entry = entry.with_addressing(Addressing::Clamped);
+        assert_eq!(entry.offset(-5, 7), Some((&4, 0, 1)));
+
+        let mut entry = grid.entry_mut(2, 1).with_addressing(Addressing::Wrapping);
+        *entry.at_offset_mut(1, 1).unwrap() = 0;
+        assert_eq!(grid.at(0, 0), Some(&0));
+    }
+
+    #[test]
+    fn queries_items() {
+        let grid = Grid::new("....\n.#..\n...#\n#...\n", |chr, _| chr);
+
+        assert!(
+            grid.positions_by(|&chr| chr == '#')
+                .eq([(1, 1), (3, 2), (0, 3)])
+        );
+        assert_eq!(grid.count_by(|&chr| chr == '#'), 3);
+        assert_eq!(grid.count_by(|&chr| chr == 'x'), 0);
+
+        let histogram = grid.histogram();
+        assert_eq!(histogram[&'.'], 13);
+        assert_eq!(histogram[&'#'], 3);
+    }
+
+    #[test]
+    fn finds_bounding_box() {
+        let grid = Grid::new("....\n..#.\n.#..\n....\n", |chr, _| chr);
+        let bounds = grid.bounding_box_by(|&chr| chr == '#').unwrap();
+
+        assert_eq!(bounds.origin(), (1, 1));
+        assert_eq!((bounds.width(), bounds.height()), (2, 2));
+        assert!(grid.bounding_box_by(|&chr| chr == 'x').is_none());
+    }
+
+    #[test]
+    fn indexes_positions_and_rows() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+
+        assert_eq!(grid[(2, 0)], 3);
+        grid[(0, 1)] = 0;
+        let pos = (1, 1);
+        grid[pos] *= 10;
+
+        assert_eq!(grid.row(1), &[0, 50, 6]);
+        grid.row_mut(0).reverse();
+        assert!(grid.rows().eq([&[3, 2, 1], &[0, 50, 6]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "position out of bounds")]
+    fn index_checks_columns() {
+        let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+        // would be (0, 1) if only the flat index was checked
+        let _ = grid[(3, 0)];
+    }
+
+    #[test]
+    fn at_checks_columns() {
+        let mut grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6], 3, 2);
+
+        assert_eq!(grid.at(3, 0), None);
+        assert_eq!(grid.at_mut(3, 0), None);
+        assert_eq!(grid.at(0, 2), None);
+        assert_eq!(grid.at(2, 1), Some(&6));
+    }
+
     #[test]
     fn iterates_row() {
         let grid = Grid::from_vec_and_dimensions(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
@@ -333,5 +1103,10 @@ mod tests {
 
         assert_eq!(grid.iter_row(69).next(), None);
         assert_eq!(grid.iter_col(420).next(), None);
+        // inside the content, but not a column
+        assert_eq!(grid.iter_col_ref(4).next(), None);
+
+        let empty: Grid<i32> = Grid::from_vec_and_dimensions(vec![], 0, 0);
+        assert_eq!(empty.iter_col(0).next(), None);
     }
 }
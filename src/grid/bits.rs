@@ -0,0 +1,359 @@
+use std::{
+    fmt::Display,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use super::Grid;
+
+const BITS: usize = u64::BITS as usize;
+
+/// Boolean grid storing one bit per cell
+///
+/// Every row starts on a fresh word, unused bits at the end of a row are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+}
+
+impl BitGrid {
+    /// Creates a grid with every cell unset
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(BITS);
+
+        Self {
+            words: vec![0; words_per_row * height],
+            width,
+            height,
+            words_per_row,
+        }
+    }
+
+    /// Sets every cell for which `pred` holds
+    pub fn from_grid<T>(grid: &Grid<T>, pred: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+
+        for ((col, row), t) in grid.iter_indexed() {
+            if pred(t) {
+                bits.set(col, row, true);
+            }
+        }
+
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let content = (0..self.height)
+            .flat_map(|row| (0..self.width).map(move |col| self.get(col, row)))
+            .collect();
+
+        Grid::from_vec_and_dimensions(content, self.width, self.height)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn word_and_mask(&self, col: usize, row: usize) -> (usize, u64) {
+        (row * self.words_per_row + col / BITS, 1 << (col % BITS))
+    }
+
+    /// Returns `None` if position is out of bounds
+    pub fn at(&self, col: usize, row: usize) -> Option<bool> {
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+
+        Some(self.get(col, row))
+    }
+
+    fn get(&self, col: usize, row: usize) -> bool {
+        let (word, mask) = self.word_and_mask(col, row);
+        self.words[word] & mask != 0
+    }
+
+    /// Panics if position is out of bounds
+    pub fn set(&mut self, col: usize, row: usize, value: bool) {
+        if col >= self.width || row >= self.height {
+            panic!("position out of bounds");
+        }
+
+        let (word, mask) = self.word_and_mask(col, row);
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// Number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Positions of set cells, row by row
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..self.height).flat_map(move |row| {
+            let words = &self.words[row * self.words_per_row..(row + 1) * self.words_per_row];
+
+            words.iter().enumerate().flat_map(move |(i, &word)| {
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    if rest == 0 {
+                        return None;
+                    }
+
+                    let bit = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    Some((i * BITS + bit, row))
+                })
+            })
+        })
+    }
+
+    /// Clears unused bits at the end of each row
+    fn mask_padding(&mut self) {
+        let used = self.width % BITS;
+        if used == 0 {
+            return;
+        }
+
+        let mask = (1 << used) - 1;
+        for row in 0..self.height {
+            self.words[(row + 1) * self.words_per_row - 1] &= mask;
+        }
+    }
+
+    /// Moves every cell by the offset, cells moved outside are lost
+    ///
+    /// In the result, `(col, row)` holds what was at `(col - dx, row - dy)`.
+    pub fn shifted(&self, dx: isize, dy: isize) -> Self {
+        let mut out = Self::new(self.width, self.height);
+        let wpr = self.words_per_row;
+
+        let (word_shift, bit_shift) = (dx.unsigned_abs() / BITS, dx.unsigned_abs() % BITS);
+
+        for row in 0..self.height {
+            let Some(src_row) = row.checked_add_signed(-dy).filter(|&r| r < self.height) else {
+                continue;
+            };
+            let src = &self.words[src_row * wpr..(src_row + 1) * wpr];
+            let dst = &mut out.words[row * wpr..(row + 1) * wpr];
+
+            // words outside of the row are zero
+            let word = |i: Option<usize>| i.and_then(|i| src.get(i)).copied().unwrap_or(0);
+
+            for (w, slot) in dst.iter_mut().enumerate() {
+                *slot = if dx >= 0 {
+                    // content moves to higher columns, i.e. more significant bits
+                    let low = word(w.checked_sub(word_shift));
+                    let below = word(w.checked_sub(word_shift + 1));
+                    if bit_shift == 0 {
+                        low
+                    } else {
+                        (low << bit_shift) | (below >> (BITS - bit_shift))
+                    }
+                } else {
+                    let high = word(Some(w + word_shift));
+                    let above = word(Some(w + word_shift + 1));
+                    if bit_shift == 0 {
+                        high
+                    } else {
+                        (high >> bit_shift) | (above << (BITS - bit_shift))
+                    }
+                };
+            }
+        }
+
+        out.mask_padding();
+        out
+    }
+
+    /// Counts set cells at each of the given offsets, for every cell
+    ///
+    /// Additions are done on whole words at a time with bit-sliced counters.
+    /// Panics if there are more than 255 offsets, so every count fits into a `u8`.
+    pub fn neighbour_counts(&self, offsets: &[(isize, isize)]) -> Grid<u8> {
+        assert!(
+            offsets.len() <= u8::MAX as usize,
+            "at most 255 offsets can be counted"
+        );
+
+        // planes[i] holds bit i of every counter
+        let mut planes: Vec<BitGrid> = Vec::new();
+
+        for &(dx, dy) in offsets {
+            // neighbour at +offset ends up under the cell when shifting by -offset
+            let mut carry = self.shifted(-dx, -dy);
+
+            for plane in planes.iter_mut() {
+                let next_carry = &*plane & &carry;
+                *plane = &*plane ^ &carry;
+                carry = next_carry;
+            }
+
+            if carry.count_ones() > 0 {
+                planes.push(carry);
+            }
+        }
+
+        let content = (0..self.height)
+            .flat_map(|row| (0..self.width).map(move |col| (col, row)))
+            .map(|(col, row)| {
+                planes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, plane)| (plane.get(col, row) as u8) << i)
+                    .sum()
+            })
+            .collect();
+
+        Grid::from_vec_and_dimensions(content, self.width, self.height)
+    }
+
+    fn zip_words(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        if (self.width, self.height) != (other.width, other.height) {
+            panic!("grid dimensions don't match");
+        }
+
+        Self {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+            ..*self
+        }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.zip_words(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.zip_words(rhs, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.zip_words(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> Self::Output {
+        let mut out = BitGrid {
+            words: self.words.iter().map(|word| !word).collect(),
+            ..*self
+        };
+        out.mask_padding();
+        out
+    }
+}
+
+/// Set cells are drawn as `#`, unset as `.`
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                write!(f, "{}", if self.get(col, row) { '#' } else { '.' })?;
+            }
+
+            if row + 1 != self.height {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automaton::MOORE;
+
+    fn parse(input: &str) -> BitGrid {
+        BitGrid::from_grid(&Grid::new(input, |chr, _| chr), |&chr| chr == '#')
+    }
+
+    #[test]
+    fn sets_and_counts() {
+        let mut bits = BitGrid::new(70, 2);
+        bits.set(0, 0, true);
+        bits.set(69, 1, true);
+        bits.set(64, 1, true);
+        bits.set(64, 1, false);
+
+        assert_eq!(bits.count_ones(), 2);
+        assert_eq!(bits.at(69, 1), Some(true));
+        assert_eq!(bits.at(70, 1), None);
+        assert!(bits.iter_ones().eq([(0, 0), (69, 1)]));
+        assert_eq!((!&bits).count_ones(), 138);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let a = parse("##..\n");
+        let b = parse(".##.\n");
+
+        assert_eq!((&a & &b).to_string(), ".#..");
+        assert_eq!((&a | &b).to_string(), "###.");
+        assert_eq!((&a ^ &b).to_string(), "#.#.");
+    }
+
+    #[test]
+    fn shifts_across_words() {
+        let mut bits = BitGrid::new(130, 2);
+        bits.set(63, 0, true);
+        bits.set(129, 0, true);
+
+        let right = bits.shifted(1, 1);
+        assert!(right.iter_ones().eq([(64, 1)]));
+
+        let left = bits.shifted(-66, 0);
+        assert!(left.iter_ones().eq([(63, 0)]));
+    }
+
+    #[test]
+    fn counts_neighbours() {
+        let bits = parse("###\n#.#\n###\n");
+        let counts = bits.neighbour_counts(&MOORE);
+
+        assert!(counts.iter_row(0).eq([2, 4, 2]));
+        assert!(counts.iter_row(1).eq([4, 8, 4]));
+
+        // the same neighbour counted 255 times still fits
+        let counts = bits.neighbour_counts(&[(0, 0); 255]);
+        assert!(counts.iter_row(1).eq([255, 0, 255]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 255 offsets")]
+    fn rejects_too_many_offsets() {
+        let _ = parse("#\n").neighbour_counts(&[(0, 0); 256]);
+    }
+}
@@ -0,0 +1,174 @@
+//! Exporting grids as images
+//!
+//! PPM is always available, PNG and animated GIF need the `png` and `gif` features.
+
+use std::io::{self, Write};
+#[cfg(feature = "gif")]
+use std::time::Duration;
+
+use super::Grid;
+
+pub type Rgb = [u8; 3];
+
+/// RGB image where every grid cell is a `scale` x `scale` square
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Packed RGB triplets, row by row
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Rasterizes a grid, `color` picks the colour of each cell
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        color: impl Fn((usize, usize), &T) -> Rgb,
+    ) -> Self {
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+        let mut pixels = Vec::with_capacity(width * height * 3);
+
+        for row in 0..grid.height() {
+            let colors: Vec<Rgb> = (0..grid.width())
+                .map(|col| color((col, row), &grid[(col, row)]))
+                .collect();
+
+            // every cell row is repeated `scale` times
+            for _ in 0..scale {
+                for rgb in &colors {
+                    for _ in 0..scale {
+                        pixels.extend_from_slice(rgb);
+                    }
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Writes a binary PPM (P6), most image viewers can open these
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels)
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(io::Error::other)?;
+
+        writer.finish().map_err(io::Error::other)
+    }
+}
+
+/// Writes frames as a looping animated GIF
+///
+/// Every frame must have the same size, delay is rounded to hundredths of a second.
+#[cfg(feature = "gif")]
+pub fn write_gif(
+    out: impl Write,
+    frames: impl IntoIterator<Item = Image>,
+    delay: Duration,
+) -> io::Result<()> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(io::Error::other("no frames to write"));
+    };
+
+    let size = |len: usize| u16::try_from(len).map_err(|_| io::Error::other("image too large"));
+    let (width, height) = (size(first.width)?, size(first.height)?);
+
+    let mut encoder = gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+
+    for image in frames {
+        if (image.width, image.height) != (width as usize, height as usize) {
+            return Err(io::Error::other("frames have different sizes"));
+        }
+
+        let mut frame = gif::Frame::from_rgb(width, height, &image.pixels);
+        frame.delay = (delay.as_millis() / 10).try_into().unwrap_or(u16::MAX);
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_vec_and_dimensions(vec![true, false, false, true], 2, 2);
+
+        Image::from_grid(&grid, 2, |_, &on| if on { [255, 0, 0] } else { [0; 3] })
+    }
+
+    #[test]
+    fn scales_cells() {
+        let image = checkerboard();
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        // second pixel row is still the first cell row
+        assert_eq!(&image.pixels()[12..18], &[255, 0, 0, 255, 0, 0]);
+        assert_eq!(&image.pixels()[18..24], &[0; 6]);
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let mut out = Vec::new();
+        checkerboard().write_ppm(&mut out).unwrap();
+
+        assert!(out.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(out.len(), 11 + 4 * 4 * 3);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn writes_png() {
+        let mut out = Vec::new();
+        checkerboard().write_png(&mut out).unwrap();
+
+        assert!(out.starts_with(b"\x89PNG"));
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn writes_gif() {
+        let mut out = Vec::new();
+        write_gif(
+            &mut out,
+            [checkerboard(), checkerboard()],
+            Duration::from_millis(100),
+        )
+        .unwrap();
+
+        assert!(out.starts_with(b"GIF89a"));
+    }
+}
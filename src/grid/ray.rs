@@ -0,0 +1,146 @@
+use super::{Addressing, Grid};
+
+/// Cells along a straight line, see [`Grid::ray`]
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    start: (usize, usize),
+    next: Option<(usize, usize)>,
+    dir: (isize, isize),
+    addressing: Addressing,
+}
+
+impl<T> Ray<'_, T> {
+    /// Changes what happens at the edges, the default is [`Addressing::Bounded`]
+    ///
+    /// A wrapping ray ends once it gets back to its starting cell,
+    /// a clamped one once it can't move any further.
+    pub fn with_addressing(mut self, addressing: Addressing) -> Self {
+        self.addressing = addressing;
+        self
+    }
+
+    /// Shorthand for [`Addressing::Wrapping`]
+    pub fn wrapping(self) -> Self {
+        self.with_addressing(Addressing::Wrapping)
+    }
+
+    fn step(&self, pos: (usize, usize)) -> Option<(usize, usize)> {
+        self.addressing
+            .resolve(pos, self.dir, (self.grid.width(), self.grid.height()))
+            // don't get stuck on the edge when clamped
+            .filter(|&next| next != pos)
+    }
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.next?;
+        let t = self.grid.at(pos.0, pos.1)?;
+
+        // also stops a ray which doesn't move at all
+        self.next = self.step(pos).filter(|&next| next != self.start);
+
+        Some((pos, t))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Walks from `pos` in direction `(dx, dy)` until leaving the grid
+    ///
+    /// The starting cell is the first item, diagonal directions work too.
+    /// Starting outside the grid gives an empty ray.
+    pub fn ray(&self, pos: (usize, usize), dir: (isize, isize)) -> Ray<'_, T> {
+        let inside = pos.0 < self.width() && pos.1 < self.height();
+
+        Ray {
+            grid: self,
+            start: pos,
+            next: inside.then_some(pos),
+            dir,
+            addressing: Addressing::Bounded,
+        }
+    }
+
+    /// Position of the first cell on the ray for which `pred` holds, including `pos` itself
+    pub fn first_hit(
+        &self,
+        pos: (usize, usize),
+        dir: (isize, isize),
+        pred: impl Fn(&T) -> bool,
+    ) -> Option<(usize, usize)> {
+        self.ray(pos, dir)
+            .find(|(_, t)| pred(t))
+            .map(|(pos, _)| pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automaton::MOORE;
+
+    fn grid() -> Grid<char> {
+        Grid::new("abc\ndef\nghi\n", |chr, _| chr)
+    }
+
+    fn collect(ray: Ray<'_, char>) -> String {
+        ray.map(|(_, &chr)| chr).collect()
+    }
+
+    #[test]
+    fn walks_all_directions() {
+        let grid = grid();
+        let rays: Vec<String> = MOORE
+            .iter()
+            .map(|&dir| collect(grid.ray((1, 1), dir)))
+            .collect();
+
+        assert_eq!(rays, ["ea", "eb", "ec", "ed", "ef", "eg", "eh", "ei"]);
+        assert_eq!(collect(grid.ray((0, 2), (1, -1))), "gec");
+        assert_eq!(collect(grid.ray((3, 0), (1, 0))), "");
+    }
+
+    #[test]
+    fn wraps_around() {
+        let grid = grid();
+
+        assert_eq!(collect(grid.ray((2, 0), (1, 0)).wrapping()), "cab");
+        assert_eq!(collect(grid.ray((0, 0), (1, 1)).wrapping()), "aei");
+        // knight-like step visits every row before coming back
+        assert_eq!(collect(grid.ray((0, 0), (2, 1)).wrapping()), "afh");
+        assert_eq!(collect(grid.ray((1, 1), (0, 0)).wrapping()), "e");
+    }
+
+    #[test]
+    fn stops_when_clamped() {
+        let grid = grid();
+        let ray = grid
+            .ray((0, 0), (1, 1))
+            .with_addressing(Addressing::Clamped);
+
+        assert_eq!(collect(ray), "aei");
+        // sliding along the bottom edge
+        let ray = grid
+            .ray((0, 1), (1, 2))
+            .with_addressing(Addressing::Clamped);
+        assert_eq!(collect(ray), "dhi");
+    }
+
+    #[test]
+    fn finds_first_hit() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.first_hit((0, 0), (0, 1), |&chr| chr > 'e'),
+            Some((0, 2))
+        );
+        assert_eq!(
+            grid.first_hit((0, 0), (1, 0), |&chr| chr == 'a'),
+            Some((0, 0))
+        );
+        assert_eq!(grid.first_hit((0, 0), (1, 0), |&chr| chr == 'd'), None);
+    }
+}
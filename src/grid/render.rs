@@ -0,0 +1,242 @@
+//! Coloured terminal output for grids
+//!
+//! ```no_run
+//! use advent_2025::grid::{Grid, render::{Color, Style}};
+//!
+//! let grid = Grid::new("..#\n.#.\n", |chr, _| chr);
+//! let rendered = grid.render(|_, &chr| match chr {
+//!     '#' => Style::new().fg(Color::Red).bold(),
+//!     _ => Style::new(),
+//! });
+//! println!("{rendered}");
+//! ```
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{self, Write},
+    time::Duration,
+};
+
+use super::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// SGR parameters, `base` is 30 for foreground and 40 for background
+    fn sgr(self, base: u8) -> String {
+        let offset = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::Rgb(r, g, b) => return format!("{};2;{r};{g};{b}", base + 8),
+        };
+
+        (base + offset).to_string()
+    }
+}
+
+/// How a single cell should look, the default is the terminal's own style
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Escape sequence which switches to this style from the default one
+    fn escape(&self) -> String {
+        let params: Vec<String> = [
+            self.bold.then(|| "1".to_string()),
+            self.fg.map(|fg| fg.sgr(30)),
+            self.bg.map(|bg| bg.sgr(40)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", params.join(";"))
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Grid together with a styling closure, see [`Grid::render`]
+pub struct Rendered<'a, T, F> {
+    grid: &'a Grid<T>,
+    style: F,
+    overlay: HashMap<(usize, usize), Style>,
+}
+
+impl<T, F> Rendered<'_, T, F>
+where
+    F: Fn((usize, usize), &T) -> Style,
+{
+    /// Draws given positions with `style` instead, e.g. a path through the grid
+    pub fn overlay(
+        mut self,
+        positions: impl IntoIterator<Item = (usize, usize)>,
+        style: Style,
+    ) -> Self {
+        self.overlay
+            .extend(positions.into_iter().map(|pos| (pos, style)));
+        self
+    }
+}
+
+impl<T, F> Display for Rendered<'_, T, F>
+where
+    T: Display,
+    F: Fn((usize, usize), &T) -> Style,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.grid.height() {
+            let mut current = Style::default();
+
+            for col in 0..self.grid.width() {
+                let t = &self.grid[(col, row)];
+                let style = match self.overlay.get(&(col, row)) {
+                    Some(style) => *style,
+                    None => (self.style)((col, row), t),
+                };
+
+                // only emit escapes when style actually changes
+                if style != current {
+                    if current != Style::default() {
+                        f.write_str(RESET)?;
+                    }
+                    f.write_str(&style.escape())?;
+                    current = style;
+                }
+
+                write!(f, "{t}")?;
+            }
+
+            if current != Style::default() {
+                f.write_str(RESET)?;
+            }
+
+            if row + 1 != self.grid.height() {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Styles every cell with a closure for printing into a terminal
+    pub fn render<F>(&self, style: F) -> Rendered<'_, T, F>
+    where
+        F: Fn((usize, usize), &T) -> Style,
+    {
+        Rendered {
+            grid: self,
+            style,
+            overlay: HashMap::new(),
+        }
+    }
+}
+
+/// Plays frames in place by moving the cursor back to the top left corner between them
+///
+/// Anything printable works as a frame, e.g. plain grids or [`Rendered`] ones.
+pub fn animate<D: Display>(
+    out: &mut impl Write,
+    frames: impl IntoIterator<Item = D>,
+    delay: Duration,
+) -> io::Result<()> {
+    // clear screen once, then only move cursor home to avoid flickering
+    write!(out, "\x1b[2J")?;
+
+    for frame in frames {
+        write!(out, "\x1b[H{frame}\x1b[J")?;
+        out.flush()?;
+        std::thread::sleep(delay);
+    }
+
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_styles_changes() {
+        let grid = Grid::new("ab\ncd\n", |chr, _| chr);
+        let rendered = grid.render(|(col, _), _| {
+            if col == 0 {
+                Style::new().fg(Color::Red)
+            } else {
+                Style::new()
+            }
+        });
+
+        assert_eq!(rendered.to_string(), "\x1b[31ma\x1b[0mb\n\x1b[31mc\x1b[0md");
+    }
+
+    #[test]
+    fn overlays_path() {
+        let grid = Grid::new("ab\n", |chr, _| chr);
+        let rendered = grid
+            .render(|_, _| Style::new())
+            .overlay([(1, 0)], Style::new().bg(Color::Rgb(1, 2, 3)).bold());
+
+        assert_eq!(rendered.to_string(), "a\x1b[1;48;2;1;2;3mb\x1b[0m");
+    }
+
+    #[test]
+    fn writes_frames() {
+        let frames = ["1", "2"];
+        let mut out = Vec::new();
+
+        animate(&mut out, frames, Duration::ZERO).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[H1\x1b[J\x1b[H2\x1b[J\n"
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::{collections::HashMap, fmt::Display};
+
+use super::Grid;
+
+/// Inclusive bounding box of occupied cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_col: i64,
+    pub min_row: i64,
+    pub max_col: i64,
+    pub max_row: i64,
+}
+
+impl Bounds {
+    fn point(col: i64, row: i64) -> Self {
+        Self {
+            min_col: col,
+            min_row: row,
+            max_col: col,
+            max_row: row,
+        }
+    }
+
+    fn include(&mut self, col: i64, row: i64) {
+        self.min_col = self.min_col.min(col);
+        self.min_row = self.min_row.min(row);
+        self.max_col = self.max_col.max(col);
+        self.max_row = self.max_row.max(row);
+    }
+
+    fn on_edge(&self, col: i64, row: i64) -> bool {
+        col == self.min_col || col == self.max_col || row == self.min_row || row == self.max_row
+    }
+
+    /// Number of columns, `None` if that doesn't fit into `usize`
+    pub fn width(&self) -> Option<usize> {
+        span(self.min_col, self.max_col)
+    }
+
+    /// Number of rows, `None` if that doesn't fit into `usize`
+    pub fn height(&self) -> Option<usize> {
+        span(self.min_row, self.max_row)
+    }
+}
+
+fn span(min: i64, max: i64) -> Option<usize> {
+    usize::try_from(min.abs_diff(max)).ok()?.checked_add(1)
+}
+
+/// Unbounded grid which only stores occupied cells
+///
+/// Coordinates are signed and may grow in any direction.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn at(&self, col: i64, row: i64) -> Option<&T> {
+        self.cells.get(&(col, row))
+    }
+
+    pub fn at_mut(&mut self, col: i64, row: i64) -> Option<&mut T> {
+        self.cells.get_mut(&(col, row))
+    }
+
+    /// Sets a cell, returning the previous value
+    pub fn insert(&mut self, col: i64, row: i64, t: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(col, row),
+            None => self.bounds = Some(Bounds::point(col, row)),
+        }
+
+        self.cells.insert((col, row), t)
+    }
+
+    /// Clears a cell, returning its value
+    pub fn remove(&mut self, col: i64, row: i64) -> Option<T> {
+        let removed = self.cells.remove(&(col, row))?;
+
+        // only cells on the edge can shrink the bounding box
+        if self.bounds.is_some_and(|bounds| bounds.on_edge(col, row)) {
+            self.bounds = self.cells.keys().fold(None, |acc, &(col, row)| {
+                let mut bounds = acc.unwrap_or(Bounds::point(col, row));
+                bounds.include(col, row);
+                Some(bounds)
+            });
+        }
+
+        Some(removed)
+    }
+
+    pub fn entry(&self, col: i64, row: i64) -> SparseEntry<'_, T> {
+        SparseEntry {
+            grid: self,
+            col,
+            row,
+        }
+    }
+
+    pub fn entry_mut(&mut self, col: i64, row: i64) -> SparseEntryMut<'_, T> {
+        SparseEntryMut {
+            grid: self,
+            col,
+            row,
+        }
+    }
+
+    /// Bounding box of all occupied cells, `None` if the grid is empty
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Iterate over occupied cells in arbitrary order
+    pub fn iter_indexed(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(&pos, t)| (pos, t))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copies cells of a dense grid, skipping ones matching `is_empty`
+    ///
+    /// Dense position `(col, row)` becomes `(col, row)` in the sparse grid.
+    pub fn from_grid(grid: &Grid<T>, is_empty: impl Fn(&T) -> bool) -> Self {
+        let mut sparse = Self::new();
+
+        for ((col, row), t) in grid.iter_indexed() {
+            if !is_empty(t) {
+                sparse.insert(col as i64, row as i64, t.clone());
+            }
+        }
+
+        sparse
+    }
+
+    /// Rasterizes occupied region into a dense grid, filling gaps with `empty`
+    ///
+    /// Returns the grid and the sparse position of its top left corner,
+    /// or `None` if there are no occupied cells or the grid would be too large to allocate.
+    pub fn to_grid(&self, empty: T) -> Option<(Grid<T>, (i64, i64))> {
+        let bounds = self.bounds?;
+        let (width, height) = (bounds.width()?, bounds.height()?);
+        let area = width.checked_mul(height)?;
+
+        let mut content = Vec::new();
+        content.try_reserve_exact(area).ok()?;
+        content.resize(area, empty);
+
+        for (&(col, row), t) in &self.cells {
+            // offsets from the minimum always fit, even if the i64 difference wouldn't
+            let dense_col = col.abs_diff(bounds.min_col) as usize;
+            let dense_row = row.abs_diff(bounds.min_row) as usize;
+            content[dense_row * width + dense_col] = t.clone();
+        }
+
+        let grid = Grid::from_vec_and_dimensions(content, width, height);
+        Some((grid, (bounds.min_col, bounds.min_row)))
+    }
+}
+
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        Self::from_grid(grid, |_| false)
+    }
+}
+
+/// Renders the occupied region, unoccupied cells are drawn as `.`
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(bounds) = self.bounds else {
+            return Ok(());
+        };
+
+        for row in bounds.min_row..=bounds.max_row {
+            for col in bounds.min_col..=bounds.max_col {
+                match self.cells.get(&(col, row)) {
+                    Some(t) => write!(f, "{t}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+
+            if row != bounds.max_row {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper for working with offsets, see [`super::GridEntry`]
+#[derive(Debug)]
+pub struct SparseEntry<'a, T> {
+    grid: &'a SparseGrid<T>,
+    col: i64,
+    row: i64,
+}
+
+impl<'a, T> SparseEntry<'a, T> {
+    pub fn at_offset(&self, col_offset: i64, row_offset: i64) -> Option<&'a T> {
+        self.offset(col_offset, row_offset).map(|thing| thing.0)
+    }
+
+    /// Returns item at offset and its true column and row index if occupied
+    pub fn offset(&self, col_offset: i64, row_offset: i64) -> Option<(&'a T, i64, i64)> {
+        let true_col = self.col.checked_add(col_offset)?;
+        let true_row = self.row.checked_add(row_offset)?;
+
+        self.grid
+            .at(true_col, true_row)
+            .map(|thing| (thing, true_col, true_row))
+    }
+}
+
+/// Mutable version of [`SparseEntry`]
+#[derive(Debug)]
+pub struct SparseEntryMut<'a, T> {
+    grid: &'a mut SparseGrid<T>,
+    col: i64,
+    row: i64,
+}
+
+impl<T> SparseEntryMut<'_, T> {
+    pub fn at_offset_mut(&mut self, col_offset: i64, row_offset: i64) -> Option<&mut T> {
+        self.offset_mut(col_offset, row_offset).map(|thing| thing.0)
+    }
+
+    /// Returns item at offset and its true column and row index if occupied
+    pub fn offset_mut(&mut self, col_offset: i64, row_offset: i64) -> Option<(&mut T, i64, i64)> {
+        let true_col = self.col.checked_add(col_offset)?;
+        let true_row = self.row.checked_add(row_offset)?;
+
+        self.grid
+            .at_mut(true_col, true_row)
+            .map(|thing| (thing, true_col, true_row))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(-2, 5, 'a');
+        grid.insert(3, -1, 'b');
+        grid.insert(0, 0, 'c');
+
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.min_col, bounds.min_row), (-2, -1));
+        assert_eq!((bounds.width(), bounds.height()), (Some(6), Some(7)));
+
+        grid.remove(-2, 5);
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.min_col, bounds.max_row), (0, 0));
+
+        grid.insert(i64::MIN, 0, 'd');
+        grid.insert(i64::MAX, 0, 'e');
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.width(), bounds.height()), (None, Some(2)));
+    }
+
+    #[test]
+    fn offsets_across_negative_coordinates() {
+        let mut grid = SparseGrid::new();
+        grid.insert(-1, -1, 7);
+
+        let entry = grid.entry(0, 0);
+        assert_eq!(entry.at_offset(-1, -1), Some(&7));
+        assert_eq!(entry.offset(-1, -1), Some((&7, -1, -1)));
+        assert_eq!(entry.at_offset(1, 1), None);
+
+        let mut entry = grid.entry_mut(0, 0);
+        *entry.at_offset_mut(-1, -1).unwrap() += 1;
+        assert_eq!(entry.offset_mut(i64::MAX, 1), None);
+        assert_eq!(grid.at(-1, -1), Some(&8));
+    }
+
+    #[test]
+    fn holds_owned_cells() {
+        let mut grid = SparseGrid::new();
+        grid.insert(0, -3, String::from("svr"));
+
+        grid.at_mut(0, -3).unwrap().push_str("-out");
+        assert_eq!(grid.entry(0, 0).at_offset(0, -3).unwrap(), "svr-out");
+        assert_eq!(grid.remove(0, -3).as_deref(), Some("svr-out"));
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn converts_to_and_from_dense() {
+        let dense = Grid::from_vec_and_dimensions(vec!['#', '.', '.', '#'], 2, 2);
+        let mut sparse = SparseGrid::from_grid(&dense, |&chr| chr == '.');
+        assert_eq!(sparse.len(), 2);
+
+        sparse.insert(-1, 0, '#');
+        assert_eq!(sparse.to_string(), "##.\n..#");
+
+        let (grid, origin) = sparse.to_grid('.').unwrap();
+        assert_eq!(origin, (-1, 0));
+        assert_eq!(grid.to_string(), "##.\n..#");
+
+        // countable, but far too many cells to allocate
+        sparse.insert(i64::MIN, 0, '#');
+        assert!(sparse.to_grid('.').is_none());
+
+        // too many cells to even count
+        sparse.insert(i64::MIN, i64::MIN, '#');
+        assert!(sparse.to_grid('.').is_none());
+
+        // both ends of a span wider than i64::MAX
+        let mut wide = SparseGrid::new();
+        wide.insert(i64::MIN, 0, 1u8);
+        wide.insert(i64::MAX, 0, 2u8);
+        assert!(wide.to_grid(0).is_none());
+    }
+}
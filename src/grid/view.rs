@@ -0,0 +1,156 @@
+use std::fmt::Display;
+
+use super::Grid;
+
+/// Borrowed rectangular part of a [`Grid`]
+///
+/// All coordinates are local to the view, `(0, 0)` is the view's top left corner.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    col: usize,
+    row: usize,
+    width: usize,
+    height: usize,
+}
+
+// derive would require T: Clone
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+/// Whether `len` cells starting at `start` stay within `limit`, without overflowing
+fn fits(start: usize, len: usize, limit: usize) -> bool {
+    start.checked_add(len).is_some_and(|end| end <= limit)
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub(super) fn new(
+        grid: &'a Grid<T>,
+        (col, row): (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        if !fits(col, width, grid.width()) || !fits(row, height, grid.height()) {
+            return None;
+        }
+
+        Some(Self {
+            grid,
+            col,
+            row,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Position of the view's top left corner in the underlying grid
+    pub fn origin(&self) -> (usize, usize) {
+        (self.col, self.row)
+    }
+
+    pub fn at(&self, col: usize, row: usize) -> Option<&'a T> {
+        if col >= self.width || row >= self.height {
+            return None;
+        }
+
+        self.grid.at(self.col + col, self.row + row)
+    }
+
+    /// Get a smaller view inside this one
+    ///
+    /// Returns `None` if the requested rectangle doesn't fit
+    pub fn view(&self, col: usize, row: usize, width: usize, height: usize) -> Option<Self> {
+        if !fits(col, width, self.width) || !fits(row, height, self.height) {
+            return None;
+        }
+
+        Some(Self {
+            grid: self.grid,
+            col: self.col + col,
+            row: self.row + row,
+            width,
+            height,
+        })
+    }
+
+    pub fn iter_indexed(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        let view = *self;
+
+        (0..view.height).flat_map(move |row| {
+            (0..view.width).map(move |col| ((col, row), view.at(col, row).unwrap()))
+        })
+    }
+}
+
+impl<'a, T: Copy> GridView<'a, T> {
+    /// Get an iterator over given row
+    ///
+    /// Returns an empty iterator if row is out of bounds
+    pub fn iter_row(&self, row: usize) -> impl Iterator<Item = T> + 'a {
+        let (grid, start, width) = (self.grid, self.col, self.width);
+        let row = if row < self.height {
+            Some(self.row + row)
+        } else {
+            None
+        };
+
+        row.into_iter()
+            .flat_map(move |row| grid.row(row)[start..start + width].iter().copied())
+    }
+
+    /// Get an iterator over given column
+    ///
+    /// Returns an empty iterator if column is out of bounds
+    pub fn iter_col(&self, col: usize) -> impl Iterator<Item = T> + 'a {
+        let (grid, start, height) = (self.grid, self.row, self.height);
+        let col = if col < self.width {
+            Some(self.col + col)
+        } else {
+            None
+        };
+
+        col.into_iter()
+            .flat_map(move |col| grid.iter_col(col).skip(start).take(height))
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Copies the viewed part into its own grid
+    pub fn to_grid(&self) -> Grid<T> {
+        let content = self.iter_indexed().map(|(_, t)| t.clone()).collect();
+
+        Grid::from_vec_and_dimensions(content, self.width, self.height)
+    }
+}
+
+impl<T> Display for GridView<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                write!(f, "{}", self.at(col, row).unwrap())?;
+            }
+
+            if row + 1 != self.height {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind, Read},
+    ops::Deref,
+};
+
+use crate::{get_filename_from_args, resolve_path};
+
+/// Puzzle input which may or may not live on the heap
+///
+/// Files are memory-mapped when the `mmap` feature is enabled,
+/// stdin and everything else gets read into an owned buffer.
+pub enum Input {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Input {
+    /// Opens input file given as the first argument, see [`crate::read_input_from_env`]
+    pub fn from_env() -> io::Result<Self> {
+        let filename = get_filename_from_args()?;
+
+        if filename == "-" {
+            let mut buffer = Vec::new();
+            io::stdin().lock().read_to_end(&mut buffer)?;
+            return Ok(Input::Owned(buffer));
+        }
+
+        let file = File::open(resolve_path(&filename)?)?;
+
+        Self::from_file(file)
+    }
+
+    #[cfg(feature = "mmap")]
+    fn from_file(file: File) -> io::Result<Self> {
+        // SAFETY: inputs are not expected to be modified while a solver is running,
+        // truncating the file underneath us is the caller's problem
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+
+        Ok(Input::Mapped(map))
+    }
+
+    #[cfg(not(feature = "mmap"))]
+    fn from_file(mut file: File) -> io::Result<Self> {
+        let mut buffer = Vec::new();
+        file.read_to_end(&mut buffer)?;
+
+        Ok(Input::Owned(buffer))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Input::Owned(buffer) => buffer,
+            #[cfg(feature = "mmap")]
+            Input::Mapped(map) => map,
+        }
+    }
+
+    /// View input as a string slice
+    ///
+    /// Fails with [`ErrorKind::InvalidData`] if the input is not UTF-8
+    pub fn as_str(&self) -> io::Result<&str> {
+        str::from_utf8(self.as_bytes()).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))
+    }
+}
+
+impl Deref for Input {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_bytes()
+    }
+}
+
+impl From<String> for Input {
+    fn from(value: String) -> Self {
+        Input::Owned(value.into_bytes())
+    }
+}
+
+/// Streams lines of the input file given as the first argument
+///
+/// Only one line is kept in memory at a time,
+/// useful for solvers which make a single pass over the input.
+pub fn read_lines_from_env() -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    read_lines(&get_filename_from_args()?)
+}
+
+fn read_lines(filename: &str) -> io::Result<impl Iterator<Item = io::Result<String>> + use<>> {
+    let reader: Box<dyn BufRead> = if filename == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(resolve_path(filename)?)?))
+    };
+
+    Ok(reader.lines())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, path::PathBuf};
+
+    use super::*;
+
+    /// Writes a file into the temp directory which is removed again on drop
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, content: &[u8]) -> Self {
+            let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+            File::create(&path).unwrap().write_all(content).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn owned_input_derefs_to_bytes() {
+        let input = Input::from(String::from("L68\nR48\n"));
+
+        assert_eq!(&input[..3], b"L68");
+        assert_eq!(input.as_str().unwrap(), "L68\nR48\n");
+    }
+
+    #[test]
+    fn reads_files() {
+        let file = TempFile::new("advent-input", b"1\n2\n");
+        let input = Input::from_file(File::open(&file.0).unwrap()).unwrap();
+
+        assert_eq!(input.as_bytes(), b"1\n2\n");
+    }
+
+    #[test]
+    fn rejects_non_utf8() {
+        let input = Input::Owned(vec![b'1', 0xff, b'\n']);
+
+        assert_eq!(input.len(), 3);
+        assert_eq!(input.as_str().unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn streams_lines() {
+        let file = TempFile::new("advent-lines", b"987\r\n811\n");
+        let lines: Vec<String> = read_lines(file.0.to_str().unwrap())
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        // BufRead::lines strips \r\n too
+        assert_eq!(lines, ["987", "811"]);
+
+        let file = TempFile::new("advent-bad-lines", &[0xff, b'\n']);
+        let mut lines = read_lines(file.0.to_str().unwrap()).unwrap();
+        assert_eq!(
+            lines.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! Rows of different lengths in a single buffer
+
+use crate::{
+    Grid,
+    parse::{ParseError, Scanner},
+};
+
+/// Rows of possibly different lengths, stored back to back
+///
+/// Like `Vec<Vec<T>>`, but with only two allocations no matter how many rows there are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Jagged<T> {
+    content: Vec<T>,
+    /// Start of every row in content, followed by the end of the last one
+    offsets: Vec<usize>,
+}
+
+impl<T> Default for Jagged<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Jagged<T> {
+    pub fn new() -> Self {
+        Self {
+            content: Vec::new(),
+            offsets: vec![0],
+        }
+    }
+
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        self.content.extend(row);
+        self.offsets.push(self.content.len());
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `None` if row is out of bounds
+    pub fn get(&self, row: usize) -> Option<&[T]> {
+        let start = *self.offsets.get(row)?;
+        let end = *self.offsets.get(row + 1)?;
+
+        Some(&self.content[start..end])
+    }
+
+    /// Panics if row is out of bounds
+    pub fn row(&self, row: usize) -> &[T] {
+        self.get(row).expect("row out of bounds")
+    }
+
+    /// Panics if row is out of bounds
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        if row >= self.len() {
+            panic!("row out of bounds");
+        }
+
+        &mut self.content[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.offsets
+            .windows(2)
+            .map(|bounds| &self.content[bounds[0]..bounds[1]])
+    }
+
+    /// Converts into a grid if every row has the same length
+    pub fn into_grid(self) -> Option<Grid<T>> {
+        let width = self.rows().next().map_or(0, |row| row.len());
+        if self.rows().any(|row| row.len() != width) {
+            return None;
+        }
+
+        let height = self.len();
+        Some(Grid::from_vec_and_dimensions(self.content, width, height))
+    }
+}
+
+impl<T, R: IntoIterator<Item = T>> FromIterator<R> for Jagged<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(rows: I) -> Self {
+        let mut jagged = Self::new();
+        for row in rows {
+            jagged.push_row(row);
+        }
+        jagged
+    }
+}
+
+impl Jagged<u8> {
+    /// Parses lines of digits which may have different lengths
+    ///
+    /// Empty lines are ignored, like in [`crate::parse::digit_grid`].
+    pub fn from_digit_lines(input: &[u8]) -> Result<Self, ParseError> {
+        let mut jagged = Self {
+            content: Vec::with_capacity(input.len()),
+            offsets: vec![0],
+        };
+
+        for mut line in Scanner::new(input).lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            while !line.is_empty() {
+                jagged.content.push(line.digit()?);
+            }
+            jagged.offsets.push(jagged.content.len());
+        }
+
+        Ok(jagged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseErrorKind;
+
+    #[test]
+    fn slices_rows() {
+        let mut jagged: Jagged<u8> = [vec![1, 2, 3], vec![], vec![4]].into_iter().collect();
+        jagged.push_row([5, 6]);
+
+        assert_eq!(jagged.len(), 4);
+        assert_eq!(jagged.row(0), &[1, 2, 3]);
+        assert_eq!(jagged.get(1), Some(&[][..]));
+        assert_eq!(jagged.get(4), None);
+
+        jagged.row_mut(3)[0] = 0;
+        assert!(jagged.rows().eq([&[1, 2, 3][..], &[], &[4], &[0, 6]]));
+    }
+
+    #[test]
+    fn parses_digit_lines() {
+        let jagged = Jagged::from_digit_lines(b"123\r\n\n45\n6789\n").unwrap();
+
+        assert!(jagged.rows().eq([&[1, 2, 3][..], &[4, 5], &[6, 7, 8, 9]]));
+        assert_eq!(jagged.clone().into_grid(), None);
+
+        let err = Jagged::from_digit_lines(b"12\n3x\n").unwrap_err();
+        assert_eq!(err.pos, 4);
+        assert_eq!(err.kind, ParseErrorKind::ExpectedDigit);
+    }
+
+    #[test]
+    fn converts_to_grid() {
+        let grid = Jagged::from_digit_lines(b"12\n34\n")
+            .unwrap()
+            .into_grid()
+            .unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(0, 1)], 3);
+        assert_eq!(Jagged::<u8>::new().into_grid().map(|g| g.height()), Some(0));
+    }
+}
@@ -3,8 +3,19 @@ use std::{
     path::{Path, PathBuf},
 };
 
+pub mod answer;
+pub mod automaton;
+pub mod cycle;
 pub mod grid;
-pub use grid::{Grid, GridEntry, GridEntryMut};
+pub mod input;
+pub mod jagged;
+pub mod memo;
+pub mod params;
+pub mod parse;
+pub use grid::{Addressing, BitGrid, Grid, GridEntry, GridEntryMut, GridView, SparseGrid};
+pub use input::{Input, read_lines_from_env};
+pub use jagged::Jagged;
+pub use params::Params;
 
 pub fn read_input_from_env() -> io::Result<String> {
     let filename = get_filename_from_args()?;
@@ -19,12 +30,9 @@ pub fn read_input_from_env() -> io::Result<String> {
 }
 
 fn get_filename_from_args() -> io::Result<String> {
-    std::env::args().nth(1).ok_or_else(|| {
-        io::Error::new(
-            io::ErrorKind::Other,
-            r#"expected input file path or "-" as first argument"#,
-        )
-    })
+    std::env::args()
+        .nth(1)
+        .ok_or_else(|| io::Error::other(r#"expected input file path or "-" as first argument"#))
 }
 
 fn resolve_path(path: impl AsRef<Path>) -> io::Result<PathBuf> {
@@ -0,0 +1,234 @@
+//! Memoization for recursive puzzle solutions
+//!
+//! ```
+//! use advent_2025::memo::Memo;
+//!
+//! fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     memo.get_or_compute(n, |memo| fib(memo, n - 1) + fib(memo, n - 2))
+//! }
+//!
+//! let mut memo = Memo::new();
+//! assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+//! assert_eq!(memo.misses(), 89);
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remembered value, without counting as a hit or a miss
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    /// Number of lookups which found a remembered value
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups which had to compute the value
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    fn lookup(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        value
+    }
+
+    /// Remembered value for `key`, or the result of `compute`
+    ///
+    /// `compute` gets the memo back, so it can recurse into other keys.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.lookup(&key) {
+            return value;
+        }
+
+        let value = compute(self);
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// Like [`Self::get_or_compute`], errors are returned as is and not remembered
+    pub fn try_get_or_compute<E>(
+        &mut self,
+        key: K,
+        compute: impl FnOnce(&mut Self) -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.lookup(&key) {
+            return Ok(value);
+        }
+
+        let value = compute(self)?;
+        self.values.insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// Computes `key` without recursion, so deep dependency chains can't overflow the stack
+    ///
+    /// `deps` lists the keys a value depends on, `combine` then gets their values
+    /// in the same order. Panics if dependencies form a cycle.
+    pub fn compute_iterative<E>(
+        &mut self,
+        key: K,
+        deps: impl Fn(&K) -> Vec<K>,
+        combine: impl Fn(&K, Vec<V>) -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.values.get(&key) {
+            self.hits += 1;
+            return Ok(value.clone());
+        }
+
+        // keys whose dependencies are on the stack above them
+        let mut in_progress = HashSet::new();
+        // dependencies are only known once a key has been expanded
+        let mut stack: Vec<(K, Option<Vec<K>>)> = vec![(key.clone(), None)];
+
+        while let Some((current, children)) = stack.pop() {
+            match children {
+                Some(children) => {
+                    let values = children
+                        .iter()
+                        .map(|child| self.values[child].clone())
+                        .collect();
+
+                    let value = combine(&current, values)?;
+                    in_progress.remove(&current);
+                    // a key can be pushed more than once, but is only computed once
+                    self.misses += 1;
+                    self.values.insert(current, value);
+                }
+                // may have been computed through some other path in the meantime
+                None if self.values.contains_key(&current) => {}
+                None => {
+                    if !in_progress.insert(current.clone()) {
+                        panic!("dependencies form a cycle");
+                    }
+
+                    let children = deps(&current);
+                    let missing: Vec<K> = children
+                        .iter()
+                        .filter(|&child| !self.values.contains_key(child))
+                        .cloned()
+                        .collect();
+                    self.hits += children.len() - missing.len();
+
+                    stack.push((current, Some(children)));
+                    stack.extend(missing.into_iter().map(|child| (child, None)));
+                }
+            }
+        }
+
+        Ok(self.values[&key].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fib(memo, n - 1) + fib(memo, n - 2))
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fib(&mut memo, 10), 55);
+        assert_eq!((memo.hits(), memo.misses()), (7, 9));
+        assert_eq!(memo.get(&10), Some(&55));
+    }
+
+    #[test]
+    fn errors_are_not_remembered() {
+        let mut memo: Memo<u8, u8> = Memo::new();
+
+        assert_eq!(memo.try_get_or_compute(1, |_| Err("nope")), Err("nope"));
+        assert_eq!(memo.try_get_or_compute(1, |_| Ok::<_, ()>(2)), Ok(2));
+        assert_eq!(memo.try_get_or_compute(1, |_| Err(())), Ok(2));
+    }
+
+    #[test]
+    fn deep_chains_use_explicit_stack() {
+        let mut memo: Memo<u32, u64> = Memo::new();
+        // far deeper than the default thread stack would allow with recursion
+        let depth = 100_000;
+
+        let sum = memo.compute_iterative(
+            0,
+            |&n| if n < depth { vec![n + 1] } else { vec![] },
+            |&n, below| Ok::<_, Infallible>(n as u64 + below.iter().sum::<u64>()),
+        );
+
+        assert_eq!(sum, Ok((depth as u64) * (depth as u64 + 1) / 2));
+        assert_eq!(memo.misses(), depth as usize + 1);
+    }
+
+    #[test]
+    fn shared_dependencies_are_computed_once() {
+        let mut memo: Memo<u64, u64> = Memo::new();
+        let deps = |&n: &u64| if n < 2 { vec![] } else { vec![n - 1, n - 2] };
+        let combine = |&n: &u64, values: Vec<u64>| {
+            Ok::<_, Infallible>(if n < 2 { n } else { values.iter().sum() })
+        };
+
+        assert_eq!(
+            memo.compute_iterative(90, deps, combine),
+            Ok(2880067194370816120)
+        );
+
+        // every value from 0 to 90 exactly once
+        assert_eq!(memo.misses(), 91);
+
+        let hits = memo.hits();
+        assert_eq!(
+            memo.compute_iterative(80, deps, combine),
+            Ok(23416728348467685)
+        );
+        assert_eq!(memo.hits(), hits + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle")]
+    fn detects_cycles() {
+        let mut memo: Memo<u8, u8> = Memo::new();
+
+        let _ = memo.compute_iterative(0, |&n| vec![(n + 1) % 3], |_, _| Ok::<_, Infallible>(0));
+    }
+}
@@ -0,0 +1,163 @@
+//! Named puzzle parameters which can be changed without editing code
+//!
+//! Solvers declare every parameter together with its default up front.
+//! Defaults can be overridden, later sources win:
+//!   1. `name = value` lines in a config file next to the input,
+//!      e.g. `example.txt.params` for `example.txt`
+//!   2. `name=value` arguments after the input file,
+//!      e.g. `cargo run --bin day08 example.txt connections=10`
+//!
+//! ```
+//! use advent_2025::Params;
+//!
+//! let params = Params::from_sources(&[("connections", "1000")], None, ["connections=10"])?;
+//! assert_eq!(params.get::<usize>("connections")?, 10);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{collections::HashMap, io, str::FromStr};
+
+use crate::{get_filename_from_args, resolve_path};
+
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    /// Every declared parameter, starting out with its default
+    values: HashMap<String, String>,
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+impl Params {
+    /// Reads overrides for the input given as first argument
+    ///
+    /// `declared` lists every parameter the solver knows as `(name, default)`,
+    /// overriding anything else is an error.
+    pub fn from_env(declared: &[(&str, &str)]) -> io::Result<Self> {
+        let filename = get_filename_from_args()?;
+
+        // stdin has no file to put a config next to
+        let config = if filename == "-" {
+            None
+        } else {
+            let mut path = resolve_path(&filename)?.into_os_string();
+            path.push(".params");
+
+            match std::fs::read_to_string(path) {
+                Ok(config) => Some(config),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+                Err(err) => return Err(err),
+            }
+        };
+
+        Self::from_sources(declared, config.as_deref(), std::env::args().skip(2))
+    }
+
+    /// Like [`Self::from_env`], with config file contents and arguments given directly
+    pub fn from_sources(
+        declared: &[(&str, &str)],
+        config: Option<&str>,
+        args: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> io::Result<Self> {
+        let mut params = Self {
+            values: declared
+                .iter()
+                .map(|&(name, default)| (name.to_string(), default.to_string()))
+                .collect(),
+        };
+
+        let config_lines = config
+            .unwrap_or_default()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for line in config_lines {
+            params.set(line)?;
+        }
+
+        for arg in args {
+            params.set(arg.as_ref())?;
+        }
+
+        Ok(params)
+    }
+
+    fn set(&mut self, assignment: &str) -> io::Result<()> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| invalid(format!("expected name=value, got {assignment:?}")))?;
+        let name = name.trim();
+
+        let Some(current) = self.values.get_mut(name) else {
+            let mut names: Vec<&String> = self.values.keys().collect();
+            names.sort();
+            return Err(invalid(format!(
+                "unknown parameter {name:?}, expected one of {names:?}"
+            )));
+        };
+
+        *current = value.trim().to_string();
+        Ok(())
+    }
+
+    /// Value of a parameter, its default if it wasn't overridden
+    ///
+    /// Panics if `name` wasn't declared, so typos can't silently fall back to a default.
+    pub fn get<T: FromStr>(&self, name: &str) -> io::Result<T> {
+        let value = self.get_str(name);
+
+        value
+            .parse()
+            .map_err(|_| invalid(format!("invalid value {value:?} for parameter {name:?}")))
+    }
+
+    /// Like [`Self::get`], but borrows text values instead of copying them
+    pub fn get_str(&self, name: &str) -> &str {
+        self.values
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter {name:?} was never declared"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[(&str, &str)] = &[("size", "1000"), ("name", "svr")];
+
+    #[test]
+    fn arguments_override_config() {
+        let config = "# example input\nsize = 10\nname = you\n";
+        let params = Params::from_sources(DECLARED, Some(config), ["size=20"]).unwrap();
+
+        assert_eq!(params.get::<u32>("size").unwrap(), 20);
+        assert_eq!(params.get_str("name"), "you");
+    }
+
+    #[test]
+    fn falls_back_to_defaults() {
+        let params = Params::from_sources(DECLARED, None, [] as [&str; 0]).unwrap();
+
+        assert_eq!(params.get::<u32>("size").unwrap(), 1000);
+        assert_eq!(params.get_str("name"), "svr");
+    }
+
+    #[test]
+    fn rejects_bad_parameters() {
+        let unknown = Params::from_sources(DECLARED, None, ["sise=10"]).unwrap_err();
+        assert!(unknown.to_string().contains("unknown parameter \"sise\""));
+
+        assert!(Params::from_sources(DECLARED, None, ["size"]).is_err());
+
+        let params = Params::from_sources(DECLARED, None, ["size=ten"]).unwrap();
+        assert!(params.get::<u32>("size").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "never declared")]
+    fn undeclared_names_panic() {
+        let params = Params::from_sources(DECLARED, None, [] as [&str; 0]).unwrap();
+        let _ = params.get::<u32>("sise");
+    }
+}
@@ -0,0 +1,588 @@
+//! Zero-allocation scanners for ASCII puzzle inputs
+//!
+//! Everything here works on bytes and reports errors as byte offsets
+//! into the original input, use [`ParseError::line_col`] to turn them into
+//! something human readable.
+
+use std::{error::Error, fmt::Display};
+
+use crate::Grid;
+
+pub mod pattern;
+pub mod table;
+pub use pattern::Pattern;
+pub use table::Table;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Expected at least one digit
+    ExpectedDigit,
+    /// Expected some non-empty text
+    ExpectedText,
+    /// Expected some specific byte
+    Expected(u8),
+    /// Number does not fit into the requested type
+    Overflow,
+    /// There were leftover bytes after a complete value
+    Trailing,
+    /// Rows of a grid had different lengths
+    Ragged,
+    /// Input had a different number of blank line separated sections
+    SectionCount { expected: usize, found: usize },
+    /// Table footer entry wasn't under exactly one column group
+    Unaligned,
+    /// Text couldn't be converted into the requested type
+    Invalid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset into the original input
+    pub pos: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(pos: usize, kind: ParseErrorKind) -> Self {
+        Self { pos, kind }
+    }
+
+    /// Converts byte offset into 1-based line and column numbers
+    pub fn line_col(&self, input: &[u8]) -> (usize, usize) {
+        let before = &input[..self.pos.min(input.len())];
+        let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+        let col = match before.iter().rposition(|&b| b == b'\n') {
+            Some(newline) => self.pos - newline,
+            None => self.pos + 1,
+        };
+
+        (line, col)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ParseErrorKind::ExpectedDigit => write!(f, "expected a digit")?,
+            ParseErrorKind::ExpectedText => write!(f, "expected some text")?,
+            ParseErrorKind::Expected(byte) => write!(f, "expected {:?}", byte as char)?,
+            ParseErrorKind::Overflow => write!(f, "number is too large")?,
+            ParseErrorKind::Trailing => write!(f, "unexpected trailing input")?,
+            ParseErrorKind::Ragged => write!(f, "grid rows have different lengths")?,
+            ParseErrorKind::SectionCount { expected, found } => {
+                write!(f, "expected {expected} sections, found {found}")?
+            }
+            ParseErrorKind::Unaligned => write!(f, "footer is not aligned with the columns")?,
+            ParseErrorKind::Invalid => write!(f, "invalid value")?,
+        }
+
+        write!(f, " at byte {}", self.pos)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Cursor over a slice of ASCII bytes
+///
+/// Scanners created by splitting remember where they started,
+/// so errors always point into the original input.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// Offset of `bytes` in the original input
+    base: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_offset(bytes, 0)
+    }
+
+    fn with_offset(bytes: &'a [u8], base: usize) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            base,
+        }
+    }
+
+    /// Absolute position of the cursor in the original input
+    pub fn pos(&self) -> usize {
+        self.base + self.pos
+    }
+
+    /// Unconsumed bytes
+    pub fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    pub fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    pub fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.pos(), kind)
+    }
+
+    /// Skips spaces and tabs, but not newlines
+    pub fn skip_spaces(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `byte` if it's next
+    pub fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::Expected(byte)))
+        }
+    }
+
+    /// Fails if there's anything left to consume
+    pub fn finish(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::Trailing))
+        }
+    }
+
+    pub fn u64(&mut self) -> Result<u64, ParseError> {
+        let start = self.pos;
+        let mut num: u64 = 0;
+
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            num = num
+                .checked_mul(10)
+                .and_then(|num| num.checked_add((digit - b'0') as u64))
+                .ok_or_else(|| ParseError::new(self.base + start, ParseErrorKind::Overflow))?;
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            return Err(self.error(ParseErrorKind::ExpectedDigit));
+        }
+
+        Ok(num)
+    }
+
+    /// Reads an integer with an optional leading `-` or `+`
+    pub fn i64(&mut self) -> Result<i64, ParseError> {
+        let start = self.pos();
+        let negative = self.eat(b'-') || {
+            self.eat(b'+');
+            false
+        };
+
+        let magnitude = self.u64()?;
+        let overflow = || ParseError::new(start, ParseErrorKind::Overflow);
+
+        if negative {
+            0i64.checked_sub_unsigned(magnitude).ok_or_else(overflow)
+        } else {
+            i64::try_from(magnitude).map_err(|_| overflow())
+        }
+    }
+
+    pub fn usize(&mut self) -> Result<usize, ParseError> {
+        let start = self.pos();
+        let num = self.u64()?;
+
+        usize::try_from(num).map_err(|_| ParseError::new(start, ParseErrorKind::Overflow))
+    }
+
+    /// Reads a single decimal digit
+    pub fn digit(&mut self) -> Result<u8, ParseError> {
+        match self.peek() {
+            Some(digit @ b'0'..=b'9') => {
+                self.pos += 1;
+                Ok(digit - b'0')
+            }
+            _ => Err(self.error(ParseErrorKind::ExpectedDigit)),
+        }
+    }
+
+    /// Consumes everything up to `delim` and the delimiter itself
+    ///
+    /// Returns the rest of the input if delimiter is not found
+    pub fn until(&mut self, delim: u8) -> Scanner<'a> {
+        let start = self.pos;
+        let rest = self.rest();
+
+        let (field, consumed) = match rest.iter().position(|&b| b == delim) {
+            Some(i) => (&rest[..i], i + 1),
+            None => (rest, rest.len()),
+        };
+        self.pos += consumed;
+
+        Scanner::with_offset(field, self.base + start)
+    }
+
+    /// Splits remaining input by `delim`
+    pub fn split(self, delim: u8) -> Split<'a> {
+        Split {
+            scanner: self,
+            delim,
+        }
+    }
+
+    /// Splits remaining input into lines, handles `\r\n`
+    ///
+    /// Like [`str::lines`], there is no empty line after a trailing newline
+    pub fn lines(self) -> Lines<'a> {
+        Lines {
+            split: self.split(b'\n'),
+        }
+    }
+
+    /// Iterator over every integer found in the remaining input, skipping everything else
+    ///
+    /// A `-` directly in front of digits makes the number negative.
+    pub fn integers(self) -> Integers<'a> {
+        Integers { scanner: self }
+    }
+}
+
+pub struct Split<'a> {
+    scanner: Scanner<'a>,
+    delim: u8,
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = Scanner<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.scanner.is_empty() {
+            return None;
+        }
+
+        Some(self.scanner.until(self.delim))
+    }
+}
+
+pub struct Lines<'a> {
+    split: Split<'a>,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Scanner<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = self.split.next()?;
+
+        if let [rest @ .., b'\r'] = line.bytes {
+            line.bytes = rest;
+        }
+
+        Some(line)
+    }
+}
+
+pub struct Integers<'a> {
+    scanner: Scanner<'a>,
+}
+
+impl Iterator for Integers<'_> {
+    type Item = Result<i64, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.scanner.rest();
+        let first_digit = rest.iter().position(u8::is_ascii_digit)?;
+
+        let negative = first_digit > 0 && rest[first_digit - 1] == b'-';
+        self.scanner.pos += if negative {
+            first_digit - 1
+        } else {
+            first_digit
+        };
+
+        let number = self.scanner.i64();
+        if number.is_err() {
+            // don't report the rest of an overflowing number as another one
+            while self.scanner.peek().is_some_and(|b| b.is_ascii_digit()) {
+                self.scanner.pos += 1;
+            }
+        }
+
+        Some(number)
+    }
+}
+
+/// Splits input into sections separated by blank lines
+///
+/// Lines with only whitespace count as blank, `\r\n` is handled
+/// and trailing whitespace is trimmed from each section.
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections { input, pos: 0 }
+}
+
+pub struct Sections<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Sections<'a> {
+    /// Byte offset of the next unconsumed line
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Collects exactly `N` sections
+    ///
+    /// Fails with [`ParseErrorKind::SectionCount`] if there are more or fewer sections,
+    /// the error points at the first extra section or the end of input.
+    pub fn collect_exact<const N: usize>(mut self) -> Result<[&'a str; N], ParseError> {
+        let mut out = [""; N];
+
+        for (found, slot) in out.iter_mut().enumerate() {
+            *slot = self.next().ok_or(ParseError::new(
+                self.input.len(),
+                ParseErrorKind::SectionCount { expected: N, found },
+            ))?;
+        }
+
+        // skip blank lines so the error points at the extra section itself
+        let extra_pos = self.skip_blank_lines();
+        if self.next().is_some() {
+            let found = N + 1 + self.count();
+
+            return Err(ParseError::new(
+                extra_pos,
+                ParseErrorKind::SectionCount { expected: N, found },
+            ));
+        }
+
+        Ok(out)
+    }
+
+    /// Length of the line at `pos` including its newline
+    fn line_len(&self) -> usize {
+        let rest = &self.input[self.pos..];
+        rest.find('\n').map_or(rest.len(), |i| i + 1)
+    }
+
+    fn is_blank_line(&self) -> bool {
+        let len = self.line_len();
+        self.input[self.pos..self.pos + len].trim().is_empty()
+    }
+
+    fn skip_blank_lines(&mut self) -> usize {
+        while self.pos < self.input.len() && self.is_blank_line() {
+            self.pos += self.line_len();
+        }
+
+        self.pos
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.skip_blank_lines();
+        if start >= self.input.len() {
+            return None;
+        }
+
+        while self.pos < self.input.len() && !self.is_blank_line() {
+            self.pos += self.line_len();
+        }
+
+        Some(self.input[start..self.pos].trim_end())
+    }
+}
+
+/// Parses whole slice as an unsigned integer
+pub fn parse_u64(bytes: &[u8]) -> Result<u64, ParseError> {
+    let mut scanner = Scanner::new(bytes);
+    let num = scanner.u64()?;
+    scanner.finish()?;
+
+    Ok(num)
+}
+
+/// Parses whole slice as a signed integer
+pub fn parse_i64(bytes: &[u8]) -> Result<i64, ParseError> {
+    let mut scanner = Scanner::new(bytes);
+    let num = scanner.i64()?;
+    scanner.finish()?;
+
+    Ok(num)
+}
+
+/// Reads a grid of single digits, e.g.
+/// ```text
+/// 123
+/// 456
+/// ```
+/// Empty lines are ignored.
+pub fn digit_grid(input: &[u8]) -> Result<Grid<u8>, ParseError> {
+    let mut content = Vec::with_capacity(input.len());
+    let mut width = None;
+    let mut height = 0;
+
+    for mut line in Scanner::new(input).lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let line_start = line.pos();
+        while !line.is_empty() {
+            content.push(line.digit()?);
+        }
+
+        let line_width = line.pos() - line_start;
+        if *width.get_or_insert(line_width) != line_width {
+            return Err(ParseError::new(line_start, ParseErrorKind::Ragged));
+        }
+        height += 1;
+    }
+
+    Ok(Grid::from_vec_and_dimensions(
+        content,
+        width.unwrap_or(0),
+        height,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_integers() {
+        let mut scanner = Scanner::new(b"123,-45,+6");
+
+        assert_eq!(scanner.u64(), Ok(123));
+        scanner.expect(b',').unwrap();
+        assert_eq!(scanner.i64(), Ok(-45));
+        scanner.expect(b',').unwrap();
+        assert_eq!(scanner.i64(), Ok(6));
+        assert!(scanner.finish().is_ok());
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let input = b"1,2\n3,x";
+        let mut lines = Scanner::new(input).lines();
+        lines.next();
+
+        let mut line = lines.next().unwrap();
+        line.u64().unwrap();
+        line.expect(b',').unwrap();
+
+        let err = line.u64().unwrap_err();
+        assert_eq!(err, ParseError::new(6, ParseErrorKind::ExpectedDigit));
+        assert_eq!(err.line_col(input), (2, 3));
+    }
+
+    #[test]
+    fn detects_overflow() {
+        assert_eq!(
+            parse_u64(b"18446744073709551616").unwrap_err().kind,
+            ParseErrorKind::Overflow
+        );
+        assert_eq!(parse_i64(b"-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(
+            parse_i64(b"9223372036854775808").unwrap_err().kind,
+            ParseErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn extracts_all_integers() {
+        let found: Vec<i64> = Scanner::new(b"p=10,-3 v=x-7 foo 42")
+            .integers()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(found, [10, -3, -7, 42]);
+
+        let found: Vec<_> = Scanner::new(b"99999999999999999999 5").integers().collect();
+        assert_eq!(
+            found,
+            [Err(ParseError::new(0, ParseErrorKind::Overflow)), Ok(5)]
+        );
+    }
+
+    #[test]
+    fn splits_lines_with_crlf() {
+        let lines: Vec<&[u8]> = Scanner::new(b"ab\r\ncd\n\nef\n")
+            .lines()
+            .map(|line| line.rest())
+            .collect();
+
+        assert_eq!(lines, [&b"ab"[..], b"cd", b"", b"ef"]);
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "1-2\r\n3-4\r\n  \r\n5\n6  \n\n\n7\n";
+        let found: Vec<&str> = sections(input).collect();
+
+        assert_eq!(found, ["1-2\r\n3-4", "5\n6", "7"]);
+        assert!(sections(input).collect_exact::<3>().is_ok());
+    }
+
+    #[test]
+    fn wrong_section_count_is_an_error() {
+        let input = "a\n\nb\n\nc";
+
+        assert_eq!(
+            sections(input).collect_exact::<2>(),
+            Err(ParseError::new(
+                6,
+                ParseErrorKind::SectionCount {
+                    expected: 2,
+                    found: 3
+                }
+            ))
+        );
+        assert_eq!(
+            sections(input).collect_exact::<4>().unwrap_err().kind,
+            ParseErrorKind::SectionCount {
+                expected: 4,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn reads_digit_grid() {
+        let grid = digit_grid(b"123\n456\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid.iter_row(1).eq([4, 5, 6]));
+
+        let err = digit_grid(b"123\n45\n").unwrap_err();
+        assert_eq!(err, ParseError::new(4, ParseErrorKind::Ragged));
+    }
+}
@@ -0,0 +1,323 @@
+//! Line formats stated as patterns, e.g. `"{u64}-{u64}"`
+//!
+//! Supported fields are `{u64}`, `{i64}`, `{usize}`, `{char}` and `{str}`.
+//! `{str}` takes everything up to whatever follows it in the pattern.
+//! A space in the pattern matches one or more spaces or tabs,
+//! every other character must match exactly.
+//!
+//! ```
+//! use advent_2025::parse::Pattern;
+//!
+//! let range = Pattern::new("{u64}-{u64}");
+//! let (start, end): (u64, u64) = range.parse("11-22").unwrap();
+//! assert_eq!((start, end), (11, 22));
+//! ```
+
+use super::{ParseError, ParseErrorKind, Scanner};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    U64,
+    I64,
+    Usize,
+    Char,
+    Str,
+}
+
+/// Value of a single parsed field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field<'a> {
+    U64(u64),
+    I64(i64),
+    Usize(usize),
+    Char(char),
+    Str(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Piece {
+    Byte(u8),
+    Spaces,
+    Field(FieldKind),
+}
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+}
+
+impl Pattern {
+    /// Compiles a pattern
+    ///
+    /// Panics if the pattern is malformed, patterns are meant to be written in code
+    pub fn new(pattern: &str) -> Self {
+        let mut pieces = Vec::new();
+        let mut rest = pattern;
+
+        while let Some(chr) = rest.chars().next() {
+            if chr == '{' {
+                let close = rest.find('}').expect("unclosed field in pattern");
+                let kind = match &rest[1..close] {
+                    "u64" => FieldKind::U64,
+                    "i64" => FieldKind::I64,
+                    "usize" => FieldKind::Usize,
+                    "char" => FieldKind::Char,
+                    "str" => FieldKind::Str,
+                    other => panic!("unknown field type {other:?} in pattern"),
+                };
+
+                if kind == FieldKind::Str
+                    && matches!(pieces.last(), Some(Piece::Field(FieldKind::Str)))
+                {
+                    panic!("two adjacent {{str}} fields are ambiguous");
+                }
+
+                pieces.push(Piece::Field(kind));
+                rest = &rest[close + 1..];
+                continue;
+            }
+
+            if chr == ' ' {
+                if pieces.last() != Some(&Piece::Spaces) {
+                    pieces.push(Piece::Spaces);
+                }
+            } else {
+                let mut buf = [0; 4];
+                pieces.extend(chr.encode_utf8(&mut buf).bytes().map(Piece::Byte));
+            }
+            rest = &rest[chr.len_utf8()..];
+        }
+
+        Self { pieces }
+    }
+
+    /// Field types in this pattern, in order
+    pub fn kinds(&self) -> impl Iterator<Item = FieldKind> {
+        self.pieces.iter().filter_map(|piece| match piece {
+            Piece::Field(kind) => Some(*kind),
+            _ => None,
+        })
+    }
+
+    /// Matches the whole line and returns every field
+    pub fn fields<'a>(&self, line: Scanner<'a>) -> Result<Vec<Field<'a>>, ParseError> {
+        let mut scanner = line;
+        let mut fields = Vec::new();
+
+        for (i, piece) in self.pieces.iter().enumerate() {
+            match piece {
+                Piece::Byte(byte) => scanner.expect(*byte)?,
+                Piece::Spaces => {
+                    if !matches!(scanner.peek(), Some(b' ' | b'\t')) {
+                        return Err(scanner.error(ParseErrorKind::Expected(b' ')));
+                    }
+                    scanner.skip_spaces();
+                }
+                Piece::Field(kind) => {
+                    let field = match kind {
+                        FieldKind::U64 => Field::U64(scanner.u64()?),
+                        FieldKind::I64 => Field::I64(scanner.i64()?),
+                        FieldKind::Usize => Field::Usize(scanner.usize()?),
+                        FieldKind::Char => Field::Char(read_char(&mut scanner)?),
+                        FieldKind::Str => {
+                            Field::Str(read_str(&mut scanner, self.pieces.get(i + 1))?)
+                        }
+                    };
+                    fields.push(field);
+                }
+            }
+        }
+
+        scanner.finish()?;
+
+        Ok(fields)
+    }
+
+    /// Matches the whole line and converts fields into `T`
+    ///
+    /// Panics if `T` doesn't agree with the pattern's field types
+    pub fn parse<'a, T: FromFields<'a>>(&self, line: &'a str) -> Result<T, ParseError> {
+        self.parse_scanner(Scanner::new(line.as_bytes()))
+    }
+
+    /// Like [`Self::parse`], but keeps error positions relative to the scanner's input
+    pub fn parse_scanner<'a, T: FromFields<'a>>(&self, line: Scanner<'a>) -> Result<T, ParseError> {
+        let fields = self.fields(line)?;
+
+        Ok(T::from_fields(&mut fields.into_iter()))
+    }
+}
+
+fn read_char(scanner: &mut Scanner<'_>) -> Result<char, ParseError> {
+    let start = scanner.pos();
+    let rest = scanner.rest();
+
+    // at most 4 bytes for a single char
+    let head = &rest[..rest.len().min(4)];
+    let valid = match str::from_utf8(head) {
+        Ok(valid) => valid,
+        Err(err) => str::from_utf8(&head[..err.valid_up_to()]).unwrap(),
+    };
+
+    let chr = valid
+        .chars()
+        .next()
+        .ok_or(ParseError::new(start, ParseErrorKind::ExpectedText))?;
+    scanner.pos += chr.len_utf8();
+
+    Ok(chr)
+}
+
+/// Reads up to whatever the next piece of the pattern is
+fn read_str<'a>(scanner: &mut Scanner<'a>, next: Option<&Piece>) -> Result<&'a str, ParseError> {
+    let start = scanner.pos();
+    let rest = scanner.rest();
+
+    let len = match next {
+        None => rest.len(),
+        Some(Piece::Byte(byte)) => rest.iter().position(|b| b == byte).unwrap_or(rest.len()),
+        Some(Piece::Spaces) => rest
+            .iter()
+            .position(|b| matches!(b, b' ' | b'\t'))
+            .unwrap_or(rest.len()),
+        // stop at the first digit or sign so that "{str}{u64}" does something sensible
+        Some(Piece::Field(_)) => rest
+            .iter()
+            .position(|b| b.is_ascii_digit() || *b == b'-' || *b == b'+')
+            .unwrap_or(rest.len()),
+    };
+
+    if len == 0 {
+        return Err(ParseError::new(start, ParseErrorKind::ExpectedText));
+    }
+
+    let text = str::from_utf8(&rest[..len])
+        .map_err(|_| ParseError::new(start, ParseErrorKind::ExpectedText))?;
+    scanner.pos += len;
+
+    Ok(text)
+}
+
+/// Conversion from a single [`Field`]
+pub trait FromField<'a>: Sized {
+    fn from_field(field: Field<'a>) -> Option<Self>;
+}
+
+macro_rules! impl_from_field {
+    ($($ty:ty => $variant:ident),*) => {
+        $(
+            impl<'a> FromField<'a> for $ty {
+                fn from_field(field: Field<'a>) -> Option<Self> {
+                    match field {
+                        Field::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field!(u64 => U64, i64 => I64, usize => Usize, char => Char, &'a str => Str);
+
+impl<'a> FromField<'a> for Field<'a> {
+    fn from_field(field: Field<'a>) -> Option<Self> {
+        Some(field)
+    }
+}
+
+/// Conversion from every field of a matched pattern
+pub trait FromFields<'a>: Sized {
+    fn from_fields(fields: &mut impl Iterator<Item = Field<'a>>) -> Self;
+}
+
+fn next_field<'a, T: FromField<'a>>(fields: &mut impl Iterator<Item = Field<'a>>) -> T {
+    let field = fields
+        .next()
+        .expect("pattern has fewer fields than requested");
+
+    T::from_field(field).unwrap_or_else(|| {
+        panic!(
+            "pattern field {field:?} can't be converted into {}",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+macro_rules! impl_from_fields {
+    ($($name:ident),+) => {
+        impl<'a, $($name: FromField<'a>),+> FromFields<'a> for ($($name,)+) {
+            fn from_fields(fields: &mut impl Iterator<Item = Field<'a>>) -> Self {
+                let tuple = ($(next_field::<$name>(fields),)+);
+                assert!(
+                    fields.next().is_none(),
+                    "pattern has more fields than requested"
+                );
+                tuple
+            }
+        }
+    };
+}
+
+impl_from_fields!(A);
+impl_from_fields!(A, B);
+impl_from_fields!(A, B, C);
+impl_from_fields!(A, B, C, D);
+impl_from_fields!(A, B, C, D, E);
+impl_from_fields!(A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ranges() {
+        let range = Pattern::new("{u64}-{u64}");
+
+        assert_eq!(range.parse::<(u64, u64)>("3-5"), Ok((3, 5)));
+        assert_eq!(
+            range.parse::<(u64, u64)>("3+5"),
+            Err(ParseError::new(1, ParseErrorKind::Expected(b'-')))
+        );
+        assert_eq!(
+            range.parse::<(u64, u64)>("3-5x"),
+            Err(ParseError::new(3, ParseErrorKind::Trailing))
+        );
+    }
+
+    #[test]
+    fn parses_strings_and_spaces() {
+        let pattern = Pattern::new("{str}: {str}");
+        let (node, outs): (&str, &str) = pattern.parse("aaa: you  hhh").unwrap();
+
+        assert_eq!(node, "aaa");
+        assert_eq!(outs, "you  hhh");
+
+        let size = Pattern::new("{usize}x{usize}: {str}");
+        let (w, h, rest): (usize, usize, &str) = size.parse("12x5:   1 0 1").unwrap();
+        assert_eq!((w, h, rest), (12, 5, "1 0 1"));
+    }
+
+    #[test]
+    fn reports_what_was_expected() {
+        let pattern = Pattern::new("{char}{i64}");
+
+        assert_eq!(pattern.parse::<(char, i64)>("L-68"), Ok(('L', -68)));
+        assert_eq!(
+            pattern.parse::<(char, i64)>("R"),
+            Err(ParseError::new(1, ParseErrorKind::ExpectedDigit))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_types_panic() {
+        let _ = Pattern::new("{u64}").parse::<(i64,)>("1");
+    }
+
+    #[test]
+    #[should_panic(expected = "more fields")]
+    fn unused_fields_panic() {
+        let _ = Pattern::new("{u64}-{u64}").parse::<(u64,)>("3-5");
+    }
+}
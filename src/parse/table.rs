@@ -0,0 +1,307 @@
+//! Whitespace aligned tables with footer rows
+//!
+//! ```text
+//! 123 328  51 64
+//!  45 64  387 23
+//!   6 98  215 314
+//! *   +   *   +
+//! ```
+//! Column groups are separated by columns which are blank on every body row,
+//! the footer rows at the bottom (here the operators) belong to the group above them.
+//!
+//! ```
+//! use advent_2025::parse::Table;
+//!
+//! let table = Table::new("1  20\n30  4\n+  *\n", 1);
+//! let ops: Vec<char> = table.footer(0).unwrap();
+//!
+//! assert_eq!(table.groups(), &[0..2, 3..5]);
+//! assert_eq!(ops, ['+', '*']);
+//! assert!(table.group_tokens(1).map(|token| token.text).eq(["20", "4"]));
+//! ```
+
+use std::{ops::Range, str::FromStr};
+
+use super::{ParseError, ParseErrorKind};
+use crate::Grid;
+
+/// Run of non-blank characters on a single line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    /// Row within the body or the footer
+    pub row: usize,
+    /// Column of the first character
+    pub col: usize,
+    /// Byte offset into the original input
+    pub pos: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    /// Byte offset into the original input
+    start: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    fn tokens(self, row: usize) -> impl Iterator<Item = Token<'a>> {
+        let bytes = self.text.as_bytes();
+        let mut col = 0;
+
+        std::iter::from_fn(move || {
+            while bytes.get(col)?.is_ascii_whitespace() {
+                col += 1;
+            }
+
+            let start = col;
+            while bytes.get(col).is_some_and(|b| !b.is_ascii_whitespace()) {
+                col += 1;
+            }
+
+            Some(Token {
+                text: &self.text[start..col],
+                row,
+                col: start,
+                pos: self.start + start,
+            })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Table<'a> {
+    body: Vec<Line<'a>>,
+    footer: Vec<Line<'a>>,
+    width: usize,
+    groups: Vec<Range<usize>>,
+}
+
+impl<'a> Table<'a> {
+    /// Splits the last `footer_rows` lines off as footer
+    ///
+    /// Trailing blank lines are ignored, lines may have different lengths.
+    pub fn new(input: &'a str, footer_rows: usize) -> Self {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in input.split_inclusive('\n') {
+            lines.push(Line {
+                start,
+                text: line.trim_end_matches(['\n', '\r']),
+            });
+            start += line.len();
+        }
+
+        while lines.last().is_some_and(|line| line.text.trim().is_empty()) {
+            lines.pop();
+        }
+
+        let footer = lines.split_off(lines.len().saturating_sub(footer_rows));
+        let body = lines;
+        let width = body
+            .iter()
+            .chain(&footer)
+            .map(|line| line.text.len())
+            .max()
+            .unwrap_or(0);
+
+        // only the body decides where groups are, footers may be misaligned
+        let mut blank = vec![true; width];
+        for line in &body {
+            for (col, byte) in line.text.bytes().enumerate() {
+                if !byte.is_ascii_whitespace() {
+                    blank[col] = false;
+                }
+            }
+        }
+
+        let mut groups = Vec::new();
+        let mut group_start = None;
+        for (col, &blank) in blank.iter().chain([&true]).enumerate() {
+            match (group_start, blank) {
+                (None, false) => group_start = Some(col),
+                (Some(start), true) => {
+                    groups.push(start..col);
+                    group_start = None;
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            body,
+            footer,
+            width,
+            groups,
+        }
+    }
+
+    /// Length of the longest line, footer included
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of body rows
+    pub fn height(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Column ranges separated by blank columns, left to right
+    pub fn groups(&self) -> &[Range<usize>] {
+        &self.groups
+    }
+
+    /// Every token of the body, row by row
+    pub fn tokens(&self) -> impl Iterator<Item = Token<'a>> {
+        self.body
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| line.tokens(row))
+    }
+
+    /// Body tokens inside given column group, top to bottom
+    ///
+    /// Panics if group is out of bounds
+    pub fn group_tokens(&self, group: usize) -> impl Iterator<Item = Token<'a>> {
+        let range = self.groups[group].clone();
+        self.tokens()
+            .filter(move |token| range.contains(&token.col))
+    }
+
+    /// Byte view of the body, short lines are padded with spaces
+    ///
+    /// Bytes rather than chars, so grid columns match the columns of groups and tokens.
+    pub fn to_grid(&self) -> Grid<u8> {
+        let content = self
+            .body
+            .iter()
+            .flat_map(|line| {
+                let padding = self.width - line.text.len();
+                line.text.bytes().chain(std::iter::repeat_n(b' ', padding))
+            })
+            .collect();
+
+        Grid::from_vec_and_dimensions(content, self.width, self.height())
+    }
+
+    /// Tokens of given footer row, left to right
+    ///
+    /// Panics if row is out of bounds
+    pub fn footer_tokens(&self, row: usize) -> impl Iterator<Item = Token<'a>> {
+        self.footer[row].tokens(row)
+    }
+
+    /// Parses given footer row into one value per column group
+    ///
+    /// Each value may start anywhere within its group, but every group needs exactly one.
+    /// Panics if row is out of bounds
+    pub fn footer<T: FromStr>(&self, row: usize) -> Result<Vec<T>, ParseError> {
+        let line = self.footer[row];
+        let mut values: Vec<Option<T>> = self.groups.iter().map(|_| None).collect();
+
+        for token in self.footer_tokens(row) {
+            let unaligned = ParseError::new(token.pos, ParseErrorKind::Unaligned);
+
+            let group = self
+                .groups
+                .iter()
+                .position(|range| range.contains(&token.col))
+                .ok_or(unaligned)?;
+            if values[group].is_some() {
+                return Err(unaligned);
+            }
+
+            let value = token
+                .text
+                .parse()
+                .map_err(|_| ParseError::new(token.pos, ParseErrorKind::Invalid))?;
+            values[group] = Some(value);
+        }
+
+        values
+            .into_iter()
+            .zip(&self.groups)
+            .map(|(value, range)| {
+                let pos = line.start + range.start.min(line.text.len());
+                value.ok_or(ParseError::new(pos, ParseErrorKind::Unaligned))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+    #[test]
+    fn finds_groups_and_tokens() {
+        let table = Table::new(EXAMPLE, 1);
+
+        assert_eq!((table.width(), table.height()), (15, 3));
+        assert_eq!(table.groups(), &[0..3, 4..7, 8..11, 12..15]);
+        assert!(
+            table
+                .group_tokens(2)
+                .map(|token| (token.text, token.col))
+                .eq([("51", 9), ("387", 8), ("215", 8)])
+        );
+
+        let token = table.tokens().nth(4).unwrap();
+        assert_eq!((token.text, token.row, token.pos), ("45", 1, 17));
+    }
+
+    #[test]
+    fn keeps_byte_view() {
+        let table = Table::new("1 2\n33\n+ *\n\n", 1);
+        let grid = table.to_grid();
+
+        assert_eq!(grid.row(1), b"33 ");
+        assert_eq!(table.groups().len(), 1);
+        assert_eq!(table.groups()[0], 0..3);
+
+        // multi-byte characters take up as many columns as they have bytes
+        let table = Table::new("é 1\n2  3\n+ *\n", 1);
+        let grid = table.to_grid();
+        assert_eq!((grid.width(), grid.height()), (4, 2));
+        assert_eq!(grid.row(1), b"2  3");
+        assert_eq!(table.groups(), &[0..2, 3..4]);
+    }
+
+    #[test]
+    fn parses_footer() {
+        let table = Table::new(EXAMPLE, 1);
+        let ops: Vec<char> = table.footer(0).unwrap();
+        assert_eq!(ops, ['*', '+', '*', '+']);
+
+        // operators may sit anywhere under their group
+        let table = Table::new("12 34\r\n 5 6\r\n +  *\r\n", 1);
+        let ops: Vec<char> = table.footer(0).unwrap();
+        assert_eq!(ops, ['+', '*']);
+    }
+
+    #[test]
+    fn reports_misaligned_footer() {
+        // operator in the blank column between groups
+        let table = Table::new("1 2\n+ *\n +\n", 2);
+        assert_eq!(table.height(), 1);
+        assert_eq!(
+            table.footer::<char>(1),
+            Err(ParseError::new(9, ParseErrorKind::Unaligned))
+        );
+
+        // second group has no operator
+        let table = Table::new("1 2\n+\n", 1);
+        assert_eq!(
+            table.footer::<char>(0),
+            Err(ParseError::new(5, ParseErrorKind::Unaligned))
+        );
+
+        let table = Table::new("1 2\n+ -\n", 1);
+        assert_eq!(
+            table.footer::<u64>(0),
+            Err(ParseError::new(4, ParseErrorKind::Invalid))
+        );
+    }
+}